@@ -0,0 +1,62 @@
+use divan::Bencher;
+use paradis::index::CheckedUnique;
+use std::hint::black_box;
+
+fn main() {
+    divan::main()
+}
+
+/// A permutation of `0 .. n`, which is dense in its bounds.
+fn dense_indices(n: usize) -> Vec<usize> {
+    // 7919 is prime, and therefore coprime with the (even) benchmark sizes
+    (0..n).map(|i| (i * 7919) % n).collect()
+}
+
+/// Indices spread out over a range much larger than their number.
+fn sparse_indices(n: usize) -> Vec<usize> {
+    (0..n).map(|i| (i * 7919) % n * 1_000).collect()
+}
+
+#[divan::bench(args = [1_000_000])]
+fn dense_hash(bencher: Bencher, n: usize) {
+    let indices = dense_indices(n);
+    bencher.bench_local(|| CheckedUnique::from_hashable_indices(black_box(&indices)).unwrap());
+}
+
+#[divan::bench(args = [1_000_000])]
+fn dense_sort(bencher: Bencher, n: usize) {
+    let indices = dense_indices(n);
+    bencher.bench_local(|| CheckedUnique::from_indices_with_sorting(black_box(&indices)).unwrap());
+}
+
+#[divan::bench(args = [1_000_000])]
+fn dense_bitmap(bencher: Bencher, n: usize) {
+    let indices = dense_indices(n);
+    bencher.bench_local(|| CheckedUnique::from_indices_with_bitmap(black_box(&indices)).unwrap());
+}
+
+#[divan::bench(args = [1_000_000])]
+fn sparse_hash(bencher: Bencher, n: usize) {
+    let indices = sparse_indices(n);
+    bencher.bench_local(|| CheckedUnique::from_hashable_indices(black_box(&indices)).unwrap());
+}
+
+#[divan::bench(args = [1_000_000])]
+fn sparse_auto(bencher: Bencher, n: usize) {
+    let indices = sparse_indices(n);
+    bencher.bench_local(|| CheckedUnique::from_indices(black_box(&indices)).unwrap());
+}
+
+#[cfg(feature = "rayon")]
+#[divan::bench(args = [1_000_000])]
+fn dense_par(bencher: Bencher, n: usize) {
+    let indices = dense_indices(n);
+    bencher.bench_local(|| CheckedUnique::par_from_indices(black_box(&indices)).unwrap());
+}
+
+#[cfg(feature = "rayon")]
+#[divan::bench(args = [1_000_000])]
+fn sparse_par(bencher: Bencher, n: usize) {
+    let indices = sparse_indices(n);
+    bencher.bench_local(|| CheckedUnique::par_from_indices(black_box(&indices)).unwrap());
+}
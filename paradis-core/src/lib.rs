@@ -14,7 +14,9 @@
 mod par_access;
 mod record_index;
 
-pub use par_access::{BoundedParAccess, IntoParAccess, LinearParAccess, ParAccess};
+pub use par_access::{
+    BoundedParAccess, IntoParAccess, LinearParAccess, ParAccess, SharedParAccess,
+};
 pub use record_index::{Bounds, RecordIndex};
 
 pub mod slice;
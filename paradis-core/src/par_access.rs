@@ -145,3 +145,16 @@ pub unsafe trait LinearParAccess: BoundedParAccess<usize> {
         self.bounds().extent
     }
 }
+
+/// An unsynchronized access whose records may be obtained any number of times concurrently.
+///
+/// This is typically the case for read-only access, for which records are shared references.
+/// Users of such an access may therefore obtain the record at the same index repeatedly,
+/// also from several threads at the same time, without violating the invariants described
+/// for [`ParAccess`].
+///
+/// # Safety
+///
+/// Obtaining the same record any number of times, with any number of previous instances
+/// still alive, must never lead to undefined behavior.
+pub unsafe trait SharedParAccess<Index: Copy>: ParAccess<Index> {}
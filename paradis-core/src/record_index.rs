@@ -1,4 +1,5 @@
 use crate::internal::Sealed;
+use std::fmt::Debug;
 use std::hash::Hash;
 
 /// A type suitable for use as an index into a collection of records.
@@ -16,23 +17,44 @@ use std::hash::Hash;
 /// *must* be implemented correctly.
 ///
 /// If two indices compare unequal, then they must not access the same record in a collection.
-pub unsafe trait RecordIndex: Sealed + Eq + Copy + Send + Sync + Ord + Hash {
-    // fn bounds_overlap(bounds1: &Bounds<Self>, bounds2: &Bounds<Self>) -> bool;
+pub unsafe trait RecordIndex: Sealed + Eq + Copy + Send + Sync + Ord + Hash + Debug {
+    /// Determine if two sets of bounds overlap, i.e. if some index is contained in both.
+    fn bounds_overlap(bounds1: &Bounds<Self>, bounds2: &Bounds<Self>) -> bool;
 
     /// Determine if a set of bounds contains another set of bounds.
     fn contains_bounds(container: &Bounds<Self>, bounds: &Bounds<Self>) -> bool;
 
+    /// Determine if a set of bounds is empty, i.e. contains no indices.
+    fn bounds_empty(bounds: &Bounds<Self>) -> bool;
+
     /// Determine if this index is contained inside the provided bounds.
     fn in_bounds(&self, bounds: &Bounds<Self>) -> bool;
 
     /// Expand these bounds to include the given index.
     fn enclose_index(bounds: &mut Bounds<Self>, index: Self);
 
+    /// Expand these bounds to include all indices in the other bounds.
+    fn enclose_bounds(bounds: &mut Bounds<Self>, other: &Bounds<Self>);
+
     /// Returns a set of bounds that are empty (zero extent).
     fn empty_bounds() -> Bounds<Self>;
 
     /// Returns a set of bounds that exactly contain only the provided index.
     fn bounds_for_index(index: Self) -> Bounds<Self>;
+
+    /// The number of indices contained in the bounds, or `None` if it does not fit in `usize`.
+    fn bounds_volume(bounds: &Bounds<Self>) -> Option<usize>;
+
+    /// The location of the index in a row-major enumeration of all indices in the bounds.
+    ///
+    /// The result is unspecified if the index is not contained in the bounds.
+    fn linear_offset(bounds: &Bounds<Self>, index: Self) -> usize;
+
+    /// The index at the given location in a row-major enumeration of all indices in the bounds.
+    ///
+    /// This is the inverse of [`linear_offset`](Self::linear_offset). The result is unspecified
+    /// if the location is not smaller than the volume of the bounds.
+    fn index_at_linear_offset(bounds: &Bounds<Self>, loc: usize) -> Self;
 }
 
 /// Bounds associated with an index type.
@@ -80,12 +102,30 @@ impl<I: RecordIndex> Bounds<I> {
         index.in_bounds(self)
     }
 
+    /// Check if these bounds overlap with `other`, i.e. if some index is contained in both.
+    ///
+    /// Index lists with non-overlapping bounds have disjoint indices.
+    pub fn overlaps(&self, other: &Bounds<I>) -> bool {
+        I::bounds_overlap(self, other)
+    }
+
+    /// Check if these bounds are empty, i.e. contain no indices.
+    pub fn is_empty(&self) -> bool {
+        I::bounds_empty(self)
+    }
+
     /// Expand these bounds — if needed — so that the given index is contained in the
     /// updated bounds.
     pub fn enclose_index(&mut self, index: I) {
         I::enclose_index(self, index)
     }
 
+    /// Expand these bounds — if needed — so that all indices in `other` are contained in the
+    /// updated bounds.
+    pub fn enclose_bounds(&mut self, other: &Bounds<I>) {
+        I::enclose_bounds(self, other)
+    }
+
     /// Constructs empty bounds (zero extent along each dimension).
     pub fn new_empty() -> Self {
         I::empty_bounds()
@@ -95,31 +135,86 @@ impl<I: RecordIndex> Bounds<I> {
     pub fn bounds_for_index(index: I) -> Self {
         I::bounds_for_index(index)
     }
+
+    /// The number of indices contained in these bounds, or `None` if it does not fit in `usize`.
+    pub fn volume(&self) -> Option<usize> {
+        I::bounds_volume(self)
+    }
+
+    /// The location of the index in a row-major enumeration of all indices in these bounds.
+    ///
+    /// The result is unspecified if the index is not contained in the bounds.
+    pub fn linear_offset(&self, index: I) -> usize {
+        I::linear_offset(self, index)
+    }
+
+    /// The index at the given location in a row-major enumeration of all indices in these bounds.
+    ///
+    /// This is the inverse of [`linear_offset`](Self::linear_offset). The result is unspecified
+    /// if the location is not smaller than the volume of the bounds.
+    pub fn index_at_linear_offset(&self, loc: usize) -> I {
+        I::index_at_linear_offset(self, loc)
+    }
 }
 
 macro_rules! impl_single_dim_index {
     ($ty:ty) => {
         unsafe impl RecordIndex for $ty {
+            #[inline]
+            fn bounds_overlap(bounds1: &Bounds<Self>, bounds2: &Bounds<Self>) -> bool {
+                // Compare with the last indices, since the end of bounds that contain the
+                // largest index does not fit in the index type
+                bounds1.extent > 0
+                    && bounds2.extent > 0
+                    && bounds1.offset <= bounds2.offset + (bounds2.extent - 1)
+                    && bounds2.offset <= bounds1.offset + (bounds1.extent - 1)
+            }
+
             #[inline]
             fn contains_bounds(container: &Bounds<Self>, bounds: &Bounds<Self>) -> bool {
-                let left_contained = container.offset <= bounds.offset;
-                let right_contained =
-                    bounds.offset + bounds.extent <= container.offset + container.extent;
-                left_contained && right_contained
+                container.offset <= bounds.offset && {
+                    let start = bounds.offset - container.offset;
+                    start <= container.extent && bounds.extent <= container.extent - start
+                }
+            }
+
+            #[inline]
+            fn bounds_empty(bounds: &Bounds<Self>) -> bool {
+                bounds.extent == 0
             }
 
             #[inline]
             fn in_bounds(&self, bounds: &Bounds<Self>) -> bool {
                 let Bounds { offset, extent } = *bounds;
                 let i = *self;
-                offset <= i && i < (offset + extent)
+                offset <= i && i - offset < extent
             }
 
             #[inline]
             fn enclose_index(bounds: &mut Bounds<Self>, index: Self) {
-                let new_offset = Self::min(bounds.offset, index);
+                Self::enclose_bounds(bounds, &Self::bounds_for_index(index))
+            }
+
+            #[inline]
+            fn enclose_bounds(bounds: &mut Bounds<Self>, other: &Bounds<Self>) {
+                if other.extent == 0 {
+                    return;
+                }
+                if bounds.extent == 0 {
+                    *bounds = *other;
+                    return;
+                }
+                // The end of bounds that contain the largest index does not fit in the index
+                // type, so enclose the last indices instead
+                let new_offset = Self::min(bounds.offset, other.offset);
+                let new_last = Self::max(
+                    bounds.offset + (bounds.extent - 1),
+                    other.offset + (other.extent - 1),
+                );
                 bounds.offset = new_offset;
-                bounds.extent = Self::max(bounds.extent, index - new_offset + 1)
+                bounds.extent = (new_last - new_offset)
+                    .checked_add(1)
+                    .expect("extent of bounds must fit in the index type");
             }
 
             #[inline]
@@ -137,6 +232,21 @@ macro_rules! impl_single_dim_index {
                     extent: 1,
                 }
             }
+
+            #[inline]
+            fn bounds_volume(bounds: &Bounds<Self>) -> Option<usize> {
+                usize::try_from(bounds.extent).ok()
+            }
+
+            #[inline]
+            fn linear_offset(bounds: &Bounds<Self>, index: Self) -> usize {
+                index.wrapping_sub(bounds.offset) as usize
+            }
+
+            #[inline]
+            fn index_at_linear_offset(bounds: &Bounds<Self>, loc: usize) -> Self {
+                bounds.offset.wrapping_add(loc as $ty)
+            }
         }
     };
 }
@@ -146,7 +256,7 @@ impl_single_dim_index!(usize);
 #[cfg(any(target_pointer_width = "32", target_pointer_width = "64",))]
 impl_single_dim_index!(u32);
 
-#[cfg(any(target_pointer_width = "64"))]
+#[cfg(target_pointer_width = "64")]
 impl_single_dim_index!(u64);
 
 /// Joins the provided list of expressions with the given separator
@@ -160,6 +270,22 @@ macro_rules! join_expressions {
 macro_rules! impl_tuple_index {
     (($($idx_type:tt),*), ($($idx:tt),*)) => {
         unsafe impl<$($idx_type: RecordIndex),*> RecordIndex for ($($idx_type),*) {
+            #[inline]
+            fn bounds_overlap(bounds1: &Bounds<Self>, bounds2: &Bounds<Self>) -> bool {
+                // First construct 1D bounds
+                let bounds1 = (
+                    $(Bounds { offset: bounds1.offset.$idx, extent: bounds1.extent.$idx }),*
+                );
+                let bounds2 = (
+                    $(Bounds { offset: bounds2.offset.$idx, extent: bounds2.extent.$idx }),*
+                );
+                // Boxes overlap if and only if they overlap along every axis
+                join_expressions!(
+                    &&;
+                    $($idx_type::bounds_overlap(&bounds1.$idx, &bounds2.$idx)),*
+                )
+            }
+
             #[inline]
             fn contains_bounds(container: &Bounds<Self>, bounds: &Bounds<Self>) -> bool {
                 // First construct 1D bounds
@@ -177,6 +303,17 @@ macro_rules! impl_tuple_index {
                 )
             }
 
+            #[inline]
+            fn bounds_empty(bounds: &Bounds<Self>) -> bool {
+                // A box is empty if it is empty along any axis
+                join_expressions!(
+                    ||;
+                    $($idx_type::bounds_empty(
+                        &Bounds { offset: bounds.offset.$idx, extent: bounds.extent.$idx }
+                    )),*
+                )
+            }
+
             #[inline]
             fn in_bounds(&self, bounds: &Bounds<Self>) -> bool {
                 // First construct 1D bounds
@@ -204,6 +341,29 @@ macro_rules! impl_tuple_index {
                 $(bounds.extent.$idx = bounds_1d.$idx.extent;)*
             }
 
+            #[inline]
+            fn enclose_bounds(bounds: &mut Bounds<Self>, other: &Bounds<Self>) {
+                if Self::bounds_empty(other) {
+                    return;
+                }
+                if Self::bounds_empty(bounds) {
+                    *bounds = *other;
+                    return;
+                }
+                // Both boxes are non-empty, so we can expand along each axis separately
+                $(
+                    let mut bounds_1d = Bounds {
+                        offset: bounds.offset.$idx,
+                        extent: bounds.extent.$idx,
+                    };
+                    bounds_1d.enclose_bounds(
+                        &Bounds { offset: other.offset.$idx, extent: other.extent.$idx }
+                    );
+                    bounds.offset.$idx = bounds_1d.offset;
+                    bounds.extent.$idx = bounds_1d.extent;
+                )*
+            }
+
             #[inline]
             fn empty_bounds() -> Bounds<Self> {
                 // First create 1D bounds
@@ -212,20 +372,65 @@ macro_rules! impl_tuple_index {
                 // Then merge
                 Bounds {
                     offset: ($(bounds_1d.$idx.offset),*),
-                    extent: ($(bounds_1d.$idx.offset),*)
+                    extent: ($(bounds_1d.$idx.extent),*)
                 }
             }
 
             #[inline]
             fn bounds_for_index(index: Self) -> Bounds<Self> {
-                // // First create 1D bounds
+                // First create 1D bounds
                 let bounds_1d = ($($idx_type::bounds_for_index(index.$idx)),*);
 
                 // Then merge
                 Bounds {
                     offset: ($(bounds_1d.$idx.offset),*),
-                    extent: ($(bounds_1d.$idx.offset),*)
+                    extent: ($(bounds_1d.$idx.extent),*)
+                }
+            }
+
+            #[inline]
+            fn bounds_volume(bounds: &Bounds<Self>) -> Option<usize> {
+                let volume = Some(1usize);
+                $(
+                    let volume = volume?.checked_mul($idx_type::bounds_volume(
+                        &Bounds { offset: bounds.offset.$idx, extent: bounds.extent.$idx }
+                    )?);
+                )*
+                volume
+            }
+
+            #[inline]
+            fn linear_offset(bounds: &Bounds<Self>, index: Self) -> usize {
+                // Row-major: the last axis varies fastest
+                let mut offset = 0usize;
+                $(
+                    let bounds_1d = Bounds { offset: bounds.offset.$idx, extent: bounds.extent.$idx };
+                    let extent = $idx_type::bounds_volume(&bounds_1d).unwrap_or(usize::MAX);
+                    offset = offset
+                        .wrapping_mul(extent)
+                        .wrapping_add($idx_type::linear_offset(&bounds_1d, index.$idx));
+                )*
+                offset
+            }
+
+            #[inline]
+            // The remainder is not read after the last axis
+            #[allow(unused_assignments)]
+            fn index_at_linear_offset(bounds: &Bounds<Self>, loc: usize) -> Self {
+                let volume = Self::bounds_volume(bounds).unwrap_or(usize::MAX);
+                if volume == 0 {
+                    return bounds.offset;
                 }
+                // Row-major: the last axis varies fastest, so the first axis has the largest stride
+                let mut stride = volume;
+                let mut remainder = loc;
+                ($({
+                    let bounds_1d = Bounds { offset: bounds.offset.$idx, extent: bounds.extent.$idx };
+                    stride /= $idx_type::bounds_volume(&bounds_1d).unwrap_or(usize::MAX);
+                    let loc_1d = remainder / stride;
+                    remainder %= stride;
+                    $idx_type::index_at_linear_offset(&bounds_1d, loc_1d)
+                }),*)
             }
         }
     };
@@ -411,4 +616,130 @@ mod tests {
         assert!(!<(usize, usize, usize)>::contains_bounds(&Bounds { offset: (0, 0, 0), extent: (2, 2, 2) },
                                                           &Bounds { offset: (2, 2, 2), extent: (1, 1, 1) }));
     }
+
+    #[rustfmt::skip]
+    #[test]
+    fn usize_enclose_index() {
+        let mut bounds = Bounds::bounds_for_index(5usize);
+        bounds.enclose_index(3);
+        assert_eq!(bounds, Bounds { offset: 3, extent: 3 });
+        bounds.enclose_index(8);
+        assert_eq!(bounds, Bounds { offset: 3, extent: 6 });
+        bounds.enclose_index(4);
+        assert_eq!(bounds, Bounds { offset: 3, extent: 6 });
+
+        let mut bounds = Bounds::<usize>::new_empty();
+        bounds.enclose_index(4);
+        assert_eq!(bounds, Bounds { offset: 4, extent: 1 });
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn usize_2dim_enclose() {
+        assert_eq!(Bounds::bounds_for_index((3usize, 7usize)), Bounds { offset: (3, 7), extent: (1, 1) });
+
+        let mut bounds = Bounds::bounds_for_index((3usize, 7usize));
+        bounds.enclose_index((1, 9));
+        assert_eq!(bounds, Bounds { offset: (1, 7), extent: (3, 3) });
+
+        // Empty bounds are ignored when enclosing bounds
+        bounds.enclose_bounds(&Bounds { offset: (20, 20), extent: (0, 4) });
+        assert_eq!(bounds, Bounds { offset: (1, 7), extent: (3, 3) });
+        bounds.enclose_bounds(&Bounds { offset: (0, 8), extent: (1, 4) });
+        assert_eq!(bounds, Bounds { offset: (0, 7), extent: (4, 5) });
+
+        let mut bounds = Bounds::<(usize, usize)>::new_empty();
+        bounds.enclose_bounds(&Bounds { offset: (2, 3), extent: (1, 1) });
+        assert_eq!(bounds, Bounds { offset: (2, 3), extent: (1, 1) });
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn bounds_overlap() {
+        assert!(Bounds { offset: 0usize, extent: 3 }.overlaps(&Bounds { offset: 2, extent: 3 }));
+        assert!(!Bounds { offset: 0usize, extent: 3 }.overlaps(&Bounds { offset: 3, extent: 3 }));
+        assert!(!Bounds { offset: 0usize, extent: 3 }.overlaps(&Bounds { offset: 1, extent: 0 }));
+
+        // Boxes overlap only if they overlap along every axis
+        let a = Bounds { offset: (0usize, 0usize), extent: (2, 2) };
+        assert!(a.overlaps(&Bounds { offset: (1, 1), extent: (2, 2) }));
+        assert!(!a.overlaps(&Bounds { offset: (1, 2), extent: (2, 2) }));
+        assert!(!a.overlaps(&Bounds { offset: (0, 0), extent: (2, 0) }));
+        assert!(Bounds { offset: (0usize, 0usize), extent: (2, 0) }.is_empty());
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn bounds_overlap_near_max() {
+        // Bounds that end at the largest index must not overflow
+        let end = Bounds { offset: usize::MAX - 2, extent: 2 };
+        assert!(end.overlaps(&Bounds { offset: usize::MAX - 1, extent: 1 }));
+        assert!(!end.overlaps(&Bounds { offset: 0, extent: 3 }));
+        assert!(Bounds { offset: 1usize, extent: usize::MAX }.overlaps(&end));
+        let max = Bounds { offset: u32::MAX, extent: 1 };
+        assert!(max.overlaps(&max));
+        assert!(!max.overlaps(&Bounds { offset: 0u32, extent: u32::MAX }));
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn bounds_at_max() {
+        // Bounds may contain the largest index, even though their end does not fit in the type
+        let mut bounds = Bounds::bounds_for_index(u32::MAX);
+        assert!(bounds.contains_index(u32::MAX));
+        bounds.enclose_index(1);
+        assert_eq!(bounds, Bounds { offset: 1, extent: u32::MAX });
+        assert!(bounds.contains_index(u32::MAX));
+        assert!(!bounds.contains_index(0));
+        assert!(bounds.contains_bounds(&Bounds { offset: u32::MAX, extent: 1 }));
+        assert!(bounds.contains_bounds(&bounds));
+        assert!(!Bounds { offset: 2u32, extent: u32::MAX - 2 }.contains_bounds(&bounds));
+
+        let mut bounds = Bounds { offset: usize::MAX - 1, extent: 2 };
+        bounds.enclose_bounds(&Bounds { offset: 5, extent: 3 });
+        assert_eq!(bounds, Bounds { offset: 5, extent: usize::MAX - 4 });
+    }
+
+    #[test]
+    #[should_panic(expected = "extent of bounds must fit in the index type")]
+    fn enclose_index_beyond_max_extent() {
+        let mut bounds = Bounds::bounds_for_index(0u32);
+        bounds.enclose_index(u32::MAX);
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn volume_and_linear_offset() {
+        let bounds = Bounds { offset: 3usize, extent: 4 };
+        assert_eq!(bounds.volume(), Some(4));
+        assert_eq!(bounds.linear_offset(5), 2);
+
+        let bounds = Bounds { offset: (1usize, 2usize, 3usize), extent: (2, 3, 4) };
+        assert_eq!(bounds.volume(), Some(24));
+        assert_eq!(bounds.linear_offset((1, 2, 3)), 0);
+        assert_eq!(bounds.linear_offset((1, 2, 4)), 1);
+        assert_eq!(bounds.linear_offset((1, 3, 3)), 4);
+        assert_eq!(bounds.linear_offset((2, 4, 6)), 23);
+
+        let bounds = Bounds { offset: (0usize, 0usize), extent: (usize::MAX, 2) };
+        assert_eq!(bounds.volume(), None);
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn index_at_linear_offset() {
+        let bounds = Bounds { offset: 3u32, extent: 4 };
+        assert_eq!(bounds.index_at_linear_offset(2), 5);
+
+        let bounds = Bounds { offset: (1usize, 2usize, 3usize), extent: (2, 3, 4) };
+        for loc in 0 .. 24 {
+            let index = bounds.index_at_linear_offset(loc);
+            assert!(bounds.contains_index(index));
+            assert_eq!(bounds.linear_offset(index), loc);
+        }
+        assert_eq!(bounds.index_at_linear_offset(4), (1, 3, 3));
+
+        let bounds = Bounds { offset: (5usize, 0u32), extent: (1, 3) };
+        assert_eq!(bounds.index_at_linear_offset(2), (5, 2));
+    }
 }
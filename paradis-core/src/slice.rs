@@ -1,6 +1,6 @@
 //! Core primitives for slices.
 use crate::par_access::ParAccess;
-use crate::{BoundedParAccess, Bounds, IntoParAccess, LinearParAccess};
+use crate::{BoundedParAccess, Bounds, IntoParAccess, LinearParAccess, SharedParAccess};
 use std::marker::PhantomData;
 
 /// Parallel access to a mutable slice.
@@ -73,3 +73,59 @@ unsafe impl<'a, T: Send> LinearParAccess for SliceParAccessMut<'a, T> {
         self.len
     }
 }
+
+/// Parallel read-only access to a slice.
+///
+/// Since the records are shared references, the same record may be obtained any number of
+/// times, as described by [`SharedParAccess`].
+///
+/// Unlike mutable slices, shared slices do not implement [`IntoParAccess`], so that calling
+/// `into_par_access` on a `Vec` or an array keeps producing mutable access.
+/// Use [`from_slice`](Self::from_slice) instead.
+#[derive(Debug)]
+pub struct SliceParAccess<'a, T> {
+    slice: &'a [T],
+}
+
+impl<'a, T> SliceParAccess<'a, T> {
+    /// Obtain parallel read-only access to a slice.
+    pub fn from_slice(slice: &'a [T]) -> Self {
+        Self { slice }
+    }
+}
+
+unsafe impl<'a, T: Sync> ParAccess<usize> for SliceParAccess<'a, T> {
+    type Record = &'a T;
+
+    #[inline(always)]
+    unsafe fn clone_access(&self) -> Self {
+        Self { slice: self.slice }
+    }
+
+    #[inline(always)]
+    unsafe fn get_unsync_unchecked(&self, index: usize) -> Self::Record {
+        unsafe { self.slice.get_unchecked(index) }
+    }
+}
+
+unsafe impl<'a, T: Sync> BoundedParAccess<usize> for SliceParAccess<'a, T> {
+    #[inline(always)]
+    fn in_bounds(&self, index: usize) -> bool {
+        index < self.slice.len()
+    }
+
+    fn bounds(&self) -> Bounds<usize> {
+        Bounds {
+            offset: 0,
+            extent: self.slice.len(),
+        }
+    }
+}
+
+unsafe impl<'a, T: Sync> SharedParAccess<usize> for SliceParAccess<'a, T> {}
+
+unsafe impl<'a, T: Sync> LinearParAccess for SliceParAccess<'a, T> {
+    fn collection_len(&self) -> usize {
+        self.slice.len()
+    }
+}
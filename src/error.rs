@@ -1,28 +1,229 @@
 //! Error types used throughout the library.
 
-use std::fmt::{Display, Formatter};
+use crate::index::IndexList;
+use paradis_core::{Bounds, RecordIndex};
+use std::fmt::{Debug, Display, Formatter};
 
 /// An error indicating that indices were out of bounds.
+///
+/// The bounds of the index list are not contained in the bounds of the access object.
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive]
-pub struct OutOfBounds;
+pub struct OutOfBounds<I> {
+    /// The bounds of the index list.
+    pub index_bounds: Bounds<I>,
+    /// The bounds of the access object.
+    pub access_bounds: Bounds<I>,
+    /// The position and value of the first index in the list that is out of bounds.
+    ///
+    /// This is `None` if the bounds of the index list are not tight,
+    /// so that no index in the list is actually out of bounds.
+    pub offending_index: Option<(usize, I)>,
+}
 
-impl Display for OutOfBounds {
+impl<I: Debug> Display for OutOfBounds<I> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "an index is out of bounds")
+        if let Some((position, index)) = &self.offending_index {
+            write!(
+                f,
+                "index {index:?} at position {position} is out of bounds, "
+            )?;
+        }
+        write!(
+            f,
+            "index bounds (offset {:?}, extent {:?}) are not contained in \
+             access bounds (offset {:?}, extent {:?})",
+            self.index_bounds.offset,
+            self.index_bounds.extent,
+            self.access_bounds.offset,
+            self.access_bounds.extent
+        )
     }
 }
 
-impl std::error::Error for OutOfBounds {}
+impl<I: Debug> std::error::Error for OutOfBounds<I> {}
 
 /// An error indicating that the indices in an index list were not unique.
+///
+/// Reports an index that occurs more than once, together with the positions of its
+/// first two occurrences. Which duplicated index is reported depends on the strategy
+/// used for checking.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct NonUniqueIndex<I> {
+    /// The duplicated index.
+    pub index: I,
+    /// The position of the first occurrence of the index.
+    pub first: usize,
+    /// The position of the second occurrence of the index.
+    pub second: usize,
+}
+
+impl<I: Debug> Display for NonUniqueIndex<I> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "index {:?} occurs at positions {} and {}",
+            self.index, self.first, self.second
+        )
+    }
+}
+
+impl<I: Debug> std::error::Error for NonUniqueIndex<I> {}
+
+impl<I: RecordIndex> NonUniqueIndex<I> {
+    /// Locate the first two occurrences of an index that is known to occur more than once.
+    ///
+    /// This takes a linear pass, which is acceptable since it only happens on the error path.
+    pub(crate) fn locate<Indices>(indices: &Indices, index: I) -> Self
+    where
+        Indices: IndexList<Index = I>,
+    {
+        let mut positions =
+            (0..indices.num_indices()).filter(|&loc| indices.get_index(loc) == index);
+        let first = positions.next();
+        let second = positions.next();
+        let (Some(first), Some(second)) = (first, second) else {
+            panic!("index {index:?} must occur more than once in the index list");
+        };
+        Self {
+            index,
+            first,
+            second,
+        }
+    }
+}
+
+/// An error indicating that the indices in an index list were not strictly increasing,
+/// i.e. not sorted and unique.
+///
+/// Reports the first position at which the list stops increasing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct NotStrictlyIncreasing {
+    /// The position of the first index that is not greater than the index before it.
+    pub position: usize,
+}
+
+impl Display for NotStrictlyIncreasing {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "indices are not strictly increasing: the index at position {} is not greater \
+             than the index at position {}",
+            self.position,
+            self.position - 1
+        )
+    }
+}
+
+impl std::error::Error for NotStrictlyIncreasing {}
+
+/// An error indicating that two index lists did not have the same number of indices.
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct NonUniqueIndex;
+#[non_exhaustive]
+pub struct LengthMismatch {
+    /// The number of indices in the first list.
+    pub first: usize,
+    /// The number of indices in the second list.
+    pub second: usize,
+}
+
+impl Display for LengthMismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "index lists have different numbers of indices ({} and {})",
+            self.first, self.second
+        )
+    }
+}
+
+impl std::error::Error for LengthMismatch {}
+
+/// An error indicating that the number of indices in an index list does not fit in `usize`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Overflow;
+
+impl Display for Overflow {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "the number of indices does not fit in usize")
+    }
+}
 
-impl Display for NonUniqueIndex {
+impl std::error::Error for Overflow {}
+
+/// Any error produced by this library.
+///
+/// Every error type in [`error`](crate::error) converts into this type, so that
+/// `?` can be used to propagate them. The type parameter is the index type of
+/// the errors that report indices.
+///
+/// # Examples
+///
+/// ```
+/// use paradis::index::IndexList;
+/// use paradis::Error;
+///
+/// fn count_pairs(rows: Vec<usize>, cols: Vec<usize>) -> Result<usize, Error<(usize, usize)>> {
+///     let pairs = rows.try_index_zip(cols)?.check_unique()?;
+///     Ok(pairs.num_indices())
+/// }
+///
+/// assert_eq!(count_pairs(vec![0, 1], vec![1, 1]), Ok(2));
+/// assert!(matches!(count_pairs(vec![0], vec![1, 1]), Err(Error::LengthMismatch(_))));
+/// assert!(matches!(count_pairs(vec![1, 1], vec![1, 1]), Err(Error::NonUniqueIndex(_))));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error<I> {
+    /// See [`OutOfBounds`].
+    OutOfBounds(OutOfBounds<I>),
+    /// See [`NonUniqueIndex`].
+    NonUniqueIndex(NonUniqueIndex<I>),
+    /// See [`NotStrictlyIncreasing`].
+    NotStrictlyIncreasing(NotStrictlyIncreasing),
+    /// See [`LengthMismatch`].
+    LengthMismatch(LengthMismatch),
+    /// See [`Overflow`].
+    Overflow(Overflow),
+}
+
+impl<I: Debug> Display for Error<I> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "indices are not unique")
+        match self {
+            Self::OutOfBounds(err) => Display::fmt(err, f),
+            Self::NonUniqueIndex(err) => Display::fmt(err, f),
+            Self::NotStrictlyIncreasing(err) => Display::fmt(err, f),
+            Self::LengthMismatch(err) => Display::fmt(err, f),
+            Self::Overflow(err) => Display::fmt(err, f),
+        }
     }
 }
 
-impl std::error::Error for NonUniqueIndex {}
+// The message of the wrapped error is displayed directly, so it is not also reported as a source
+impl<I: Debug> std::error::Error for Error<I> {}
+
+macro_rules! impl_from_error {
+    ($variant:ident) => {
+        impl<I> From<$variant<I>> for Error<I> {
+            fn from(err: $variant<I>) -> Self {
+                Self::$variant(err)
+            }
+        }
+    };
+    ($variant:ident without index) => {
+        impl<I> From<$variant> for Error<I> {
+            fn from(err: $variant) -> Self {
+                Self::$variant(err)
+            }
+        }
+    };
+}
+
+impl_from_error!(OutOfBounds);
+impl_from_error!(NonUniqueIndex);
+impl_from_error!(NotStrictlyIncreasing without index);
+impl_from_error!(LengthMismatch without index);
+impl_from_error!(Overflow without index);
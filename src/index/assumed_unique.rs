@@ -27,6 +27,10 @@ unsafe impl<Indices: IndexList> IndexList for AssumedUnique<Indices> {
         self.indices.num_indices()
     }
 
+    fn contiguous_bounds(&self) -> Option<Bounds<Self::Index>> {
+        self.indices.contiguous_bounds()
+    }
+
     fn bounds(&self) -> Option<Bounds<Self::Index>> {
         self.indices.bounds()
     }
@@ -2,9 +2,13 @@ use crate::error::NonUniqueIndex;
 use crate::index::{IndexList, UniqueIndexList};
 use crate::RecordIndex;
 use paradis_core::Bounds;
-use std::collections::HashSet;
+use std::collections::HashMap;
 
 /// A list of indices that are checked to be unique.
+///
+/// The bounds of the indices are determined during the check. Constructors that compute the
+/// bounds panic if their extent does not fit in the index type, for example for `u32` indices
+/// that include both `0` and `u32::MAX`.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CheckedUnique<Indices: IndexList> {
     indices: Indices,
@@ -23,20 +27,65 @@ impl<Indices: IndexList> CheckedUnique<Indices> {
     }
 }
 
+/// Use a bitmap if it needs at most this many bits per index.
+const MAX_BITMAP_BITS_PER_INDEX: usize = 32;
+
 impl<Indices> CheckedUnique<Indices>
 where
     Indices: IndexList,
     Indices::Index: RecordIndex,
 {
-    /// Check that the provided indices are unique.
+    /// Check that the provided indices are unique, with an automatically selected strategy.
+    ///
+    /// If the bounds of the indices are small compared to the number of indices, the check
+    /// uses a bitmap over the bounds (see [`from_indices_with_bitmap`](Self::from_indices_with_bitmap)).
+    /// Otherwise, it sorts a copy of the indices
+    /// (see [`from_indices_with_sorting`](Self::from_indices_with_sorting)).
+    /// Bounds are computed in a separate pass if the index list does not provide them.
+    ///
+    /// Hashing (see [`from_hashable_indices`](Self::from_hashable_indices)) is never selected,
+    /// not even for a few indices spread over huge bounds. Sorting a copy of the indices is as
+    /// fast for short lists, and several times faster for long lists, whose hash maps no longer
+    /// fit in cache.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the indices are not unique.
+    pub fn from_indices(indices: Indices) -> Result<Self, NonUniqueIndex<Indices::Index>> {
+        let n = indices.num_indices();
+        if n == 0 {
+            return Ok(Self {
+                indices,
+                bounds: Indices::Index::empty_bounds(),
+            });
+        }
+
+        let bounds = indices.bounds().unwrap_or_else(|| compute_bounds(&indices));
+        match bounds.volume() {
+            // This includes the case where there are more indices than distinct indices inside
+            // the bounds. The bitmap is then smaller than the index list, and locates a duplicate
+            Some(volume) if volume / MAX_BITMAP_BITS_PER_INDEX <= n => {
+                check_unique_with_bitmap(&indices, &bounds)?;
+                Ok(Self { indices, bounds })
+            }
+            _ => Self::from_indices_with_sorting(indices),
+        }
+    }
+
+    /// Check that the provided indices are unique, using a [`HashMap`].
     ///
     /// On success, wrap this object in [`CheckedUnique`]. The bounds of the index list
     /// are computed at the same time.
     ///
+    /// Unlike the other strategies, this reports the first duplicate in the order of the list
+    /// without a second pass. It is not selected by [`from_indices`](Self::from_indices), since
+    /// sorting is generally faster, but may be preferable for index types that are cheap to hash
+    /// and expensive to compare.
+    ///
     /// # Errors
     ///
     /// An error is returned if the indices are not unique.
-    pub fn from_hashable_indices(indices: Indices) -> Result<Self, NonUniqueIndex> {
+    pub fn from_hashable_indices(indices: Indices) -> Result<Self, NonUniqueIndex<Indices::Index>> {
         let n = indices.num_indices();
         if n == 0 {
             return Ok(Self {
@@ -47,17 +96,256 @@ where
 
         let mut bounds = Bounds::bounds_for_index(indices.get_index(0));
         // TODO: Use faster hash? ahash?
-        let mut set = HashSet::with_capacity(n);
+        let mut first_positions = HashMap::with_capacity(n);
         for loc in 0..n {
             let idx = indices.get_index(loc);
             bounds.enclose_index(idx);
-            if !set.insert(idx) {
-                return Err(NonUniqueIndex);
+            if let Some(&first) = first_positions.get(&idx) {
+                return Err(NonUniqueIndex {
+                    index: idx,
+                    first,
+                    second: loc,
+                });
             }
+            first_positions.insert(idx, loc);
+        }
+
+        Ok(Self { indices, bounds })
+    }
+
+    /// Check that the provided indices are unique, by sorting a copy of the indices.
+    ///
+    /// This takes `O(n log n)` time and requires memory for a copy of the indices.
+    /// The bounds of the index list are computed at the same time.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the indices are not unique.
+    pub fn from_indices_with_sorting(
+        indices: Indices,
+    ) -> Result<Self, NonUniqueIndex<Indices::Index>> {
+        let n = indices.num_indices();
+        if n == 0 {
+            return Ok(Self {
+                indices,
+                bounds: Indices::Index::empty_bounds(),
+            });
+        }
+
+        let mut bounds = Bounds::bounds_for_index(indices.get_index(0));
+        let mut sorted = Vec::with_capacity(n);
+        for loc in 0..n {
+            let idx = indices.get_index(loc);
+            bounds.enclose_index(idx);
+            sorted.push(idx);
+        }
+        sorted.sort_unstable();
+        if let Some(pair) = sorted.windows(2).find(|pair| pair[0] == pair[1]) {
+            return Err(NonUniqueIndex::locate(&indices, pair[0]));
+        }
+
+        Ok(Self { indices, bounds })
+    }
+
+    /// Check that the provided indices are unique, using a bitmap over their bounds.
+    ///
+    /// This takes `O(n + v)` time and `v` bits of memory, where `v` is the number of indices
+    /// contained in the bounds. It is therefore very efficient when the bounds are small
+    /// compared to the number of indices. Bounds are computed in a separate pass if the index list
+    /// does not provide them.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the indices are not unique.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of indices contained in the bounds does not fit in `usize`.
+    pub fn from_indices_with_bitmap(
+        indices: Indices,
+    ) -> Result<Self, NonUniqueIndex<Indices::Index>> {
+        if indices.num_indices() == 0 {
+            return Ok(Self {
+                indices,
+                bounds: Indices::Index::empty_bounds(),
+            });
         }
 
+        let bounds = indices.bounds().unwrap_or_else(|| compute_bounds(&indices));
+        check_unique_with_bitmap(&indices, &bounds)?;
         Ok(Self { indices, bounds })
     }
+
+    /// Check that the provided indices are unique, in parallel.
+    ///
+    /// This is the parallel counterpart of [`from_indices`](Self::from_indices). Bounds are
+    /// computed with a parallel reduction if the index list does not provide them. Duplicates are
+    /// detected with an atomic bitmap over the bounds if the bounds are small compared to the
+    /// number of indices, and otherwise with a parallel sort of a copy of the indices.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the indices are not unique.
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    #[cfg(feature = "rayon")]
+    pub fn par_from_indices(indices: Indices) -> Result<Self, NonUniqueIndex<Indices::Index>> {
+        let n = indices.num_indices();
+        if n == 0 {
+            return Ok(Self {
+                indices,
+                bounds: Indices::Index::empty_bounds(),
+            });
+        }
+
+        let bounds = indices
+            .bounds()
+            .unwrap_or_else(|| par::compute_bounds(&indices));
+        match bounds.volume() {
+            // As in `from_indices`, this includes the case where there are more indices than
+            // distinct indices inside the bounds
+            Some(volume) if volume / MAX_BITMAP_BITS_PER_INDEX <= n => {
+                par::check_unique_with_bitmap(&indices, &bounds)?;
+                Ok(Self { indices, bounds })
+            }
+            _ => {
+                par::check_unique_with_sorting(&indices)?;
+                Ok(Self { indices, bounds })
+            }
+        }
+    }
+
+    /// Check that an index list made up of two unique parts is unique.
+    ///
+    /// If the bounds of the two parts are known and do not overlap, the parts are
+    /// disjoint and no further checking is necessary. Otherwise, falls back to checking
+    /// the entire list.
+    pub(crate) fn from_unique_parts(
+        indices: Indices,
+        bounds_a: Option<Bounds<Indices::Index>>,
+        bounds_b: Option<Bounds<Indices::Index>>,
+    ) -> Result<Self, NonUniqueIndex<Indices::Index>> {
+        match (bounds_a, bounds_b) {
+            (Some(mut bounds), Some(bounds_b)) if !bounds.overlaps(&bounds_b) => {
+                bounds.enclose_bounds(&bounds_b);
+                Ok(Self { indices, bounds })
+            }
+            _ => Self::from_indices(indices),
+        }
+    }
+}
+
+/// Compute the tightest bounds of a non-empty index list.
+fn compute_bounds<Indices>(indices: &Indices) -> Bounds<Indices::Index>
+where
+    Indices: IndexList,
+    Indices::Index: RecordIndex,
+{
+    let mut bounds = Bounds::bounds_for_index(indices.get_index(0));
+    for loc in 1..indices.num_indices() {
+        bounds.enclose_index(indices.get_index(loc));
+    }
+    bounds
+}
+
+/// Check that the indices in the list, which must be contained in the given bounds, are unique.
+fn check_unique_with_bitmap<Indices>(
+    indices: &Indices,
+    bounds: &Bounds<Indices::Index>,
+) -> Result<(), NonUniqueIndex<Indices::Index>>
+where
+    Indices: IndexList,
+    Indices::Index: RecordIndex,
+{
+    let volume = bounds
+        .volume()
+        .expect("number of indices in bounds must fit in usize");
+    let mut bitmap = vec![0u64; volume.div_ceil(64)];
+    for loc in 0..indices.num_indices() {
+        let idx = indices.get_index(loc);
+        let offset = bounds.linear_offset(idx);
+        let (word, bit) = (offset / 64, 1 << (offset % 64));
+        if bitmap[word] & bit != 0 {
+            return Err(NonUniqueIndex::locate(indices, idx));
+        }
+        bitmap[word] |= bit;
+    }
+    Ok(())
+}
+
+#[cfg(feature = "rayon")]
+mod par {
+    use crate::error::NonUniqueIndex;
+    use crate::index::IndexList;
+    use crate::RecordIndex;
+    use paradis_core::Bounds;
+    use rayon::iter::{IntoParallelIterator, ParallelIterator};
+    use rayon::slice::{ParallelSlice, ParallelSliceMut};
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    /// Compute the tightest bounds of an index list with a parallel reduction.
+    pub(super) fn compute_bounds<Indices>(indices: &Indices) -> Bounds<Indices::Index>
+    where
+        Indices: IndexList,
+        Indices::Index: RecordIndex,
+    {
+        (0..indices.num_indices())
+            .into_par_iter()
+            .map(|loc| Bounds::bounds_for_index(indices.get_index(loc)))
+            .reduce(Bounds::new_empty, |mut bounds, other| {
+                bounds.enclose_bounds(&other);
+                bounds
+            })
+    }
+
+    /// Check that the indices in the list, which must be contained in the given bounds, are
+    /// unique, using a bitmap that is shared between threads.
+    pub(super) fn check_unique_with_bitmap<Indices>(
+        indices: &Indices,
+        bounds: &Bounds<Indices::Index>,
+    ) -> Result<(), NonUniqueIndex<Indices::Index>>
+    where
+        Indices: IndexList,
+        Indices::Index: RecordIndex,
+    {
+        let volume = bounds
+            .volume()
+            .expect("number of indices in bounds must fit in usize");
+        let bitmap: Vec<_> = (0..volume.div_ceil(64))
+            .map(|_| AtomicU64::new(0))
+            .collect();
+        let duplicate = (0..indices.num_indices())
+            .into_par_iter()
+            .map(|loc| indices.get_index(loc))
+            .find_any(|&idx| {
+                let offset = bounds.linear_offset(idx);
+                let (word, bit) = (offset / 64, 1 << (offset % 64));
+                // Exactly one thread observes the bit as unset, so a duplicate is always detected
+                bitmap[word].fetch_or(bit, Ordering::Relaxed) & bit != 0
+            });
+        match duplicate {
+            Some(idx) => Err(NonUniqueIndex::locate(indices, idx)),
+            None => Ok(()),
+        }
+    }
+
+    /// Check that the indices in the list are unique by sorting a copy in parallel.
+    pub(super) fn check_unique_with_sorting<Indices>(
+        indices: &Indices,
+    ) -> Result<(), NonUniqueIndex<Indices::Index>>
+    where
+        Indices: IndexList,
+        Indices::Index: RecordIndex,
+    {
+        let mut sorted: Vec<_> = (0..indices.num_indices())
+            .into_par_iter()
+            .map(|loc| indices.get_index(loc))
+            .collect();
+        sorted.par_sort_unstable();
+        match sorted.par_windows(2).find_any(|pair| pair[0] == pair[1]) {
+            Some(pair) => Err(NonUniqueIndex::locate(indices, pair[0])),
+            None => Ok(()),
+        }
+    }
 }
 
 unsafe impl<Indices> IndexList for CheckedUnique<Indices>
@@ -77,6 +365,10 @@ where
         self.indices.num_indices()
     }
 
+    fn contiguous_bounds(&self) -> Option<Bounds<Self::Index>> {
+        self.indices.contiguous_bounds()
+    }
+
     fn bounds(&self) -> Option<Bounds<Self::Index>> {
         Some(self.bounds)
     }
@@ -0,0 +1,140 @@
+//! Colouring of items that conflict when they share a resource.
+//!
+//! In finite element assembly, for example, every element writes to the nodes it touches,
+//! so two elements that share a node cannot be processed at the same time. Colouring the
+//! elements such that no two elements of the same colour share a node gives colour classes
+//! whose elements can be processed in parallel, one colour after another.
+use crate::index::{DisjointClasses, SortedUnique};
+
+/// A colouring of items, such that no two items of the same colour share a resource.
+///
+/// Items are identified by their position `0 .. n` in the sequence passed to the constructor.
+/// Each colour class is a [`SortedUnique`] list of item indices, and can therefore be
+/// used with [`narrow_access`](crate::index::narrow_access) and other facilities that require
+/// a [`UniqueIndexList`](crate::index::UniqueIndexList). Since every item has exactly one colour,
+/// the classes are provided as [`DisjointClasses`].
+///
+/// # Examples
+///
+/// ```
+/// use paradis::index::coloring::Coloring;
+/// use paradis::index::IndexList;
+///
+/// // Four triangles in a strip, identified by their node indices
+/// let elements = [[0, 1, 2], [1, 2, 3], [2, 3, 4], [3, 4, 5]];
+/// let coloring = Coloring::greedy(elements);
+///
+/// // Consecutive triangles share an edge, and triangles two apart share a node
+/// assert_eq!(coloring.num_colors(), 3);
+/// assert_eq!(coloring.class(0).get_inner(), &vec![0, 3]);
+/// assert_eq!(coloring.class(1).get_inner(), &vec![1]);
+/// assert_eq!(coloring.class(2).get_inner(), &vec![2]);
+/// assert_eq!(coloring.color_of(3), 0);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Coloring {
+    colors: Vec<usize>,
+    classes: DisjointClasses<SortedUnique<Vec<usize>>>,
+}
+
+impl Coloring {
+    /// Colour items greedily, given the resources used by each item.
+    ///
+    /// The items are visited in order, and each item is assigned the smallest colour that is
+    /// not yet used by an item that shares a resource with it. The number of colours is at
+    /// most one more than the largest number of other items that any item conflicts with.
+    /// This takes time proportional to the total number of resources of all items, times
+    /// the number of items that use each resource.
+    ///
+    /// Resources are indices that may appear in any order, and may be repeated within an item.
+    /// Storage proportional to the largest resource index is allocated.
+    pub fn greedy<Items>(items: Items) -> Self
+    where
+        Items: IntoIterator,
+        Items::Item: IntoIterator<Item = usize>,
+    {
+        // The colours of the items visited so far that use each resource
+        let mut resource_colors: Vec<Vec<usize>> = Vec::new();
+        // forbidden[c] == item + 1 if colour c is used by a neighbour of the current item
+        let mut forbidden: Vec<usize> = Vec::new();
+        let mut colors = Vec::new();
+        let mut classes: Vec<Vec<usize>> = Vec::new();
+
+        for (item, resources) in items.into_iter().enumerate() {
+            let resources: Vec<usize> = resources.into_iter().collect();
+            for &resource in &resources {
+                for &color in resource_colors.get(resource).into_iter().flatten() {
+                    forbidden[color] = item + 1;
+                }
+            }
+
+            let color = (0..)
+                .find(|&color| forbidden.get(color) != Some(&(item + 1)))
+                .expect("there is always a colour that is not forbidden");
+            if color == classes.len() {
+                classes.push(Vec::new());
+                forbidden.push(0);
+            }
+            classes[color].push(item);
+            colors.push(color);
+
+            for resource in resources {
+                if resource >= resource_colors.len() {
+                    resource_colors.resize_with(resource + 1, Vec::new);
+                }
+                resource_colors[resource].push(color);
+            }
+        }
+
+        let classes = classes
+            .into_iter()
+            .map(|class| {
+                SortedUnique::from_sorted_indices(class)
+                    .expect("items are added to their class in increasing order")
+            })
+            .collect();
+        // SAFETY: Every item is added to exactly one class
+        let classes = unsafe { DisjointClasses::from_classes_unchecked(classes) };
+        Self { colors, classes }
+    }
+
+    /// The number of colours used.
+    pub fn num_colors(&self) -> usize {
+        self.classes.len()
+    }
+
+    /// The number of items that were coloured.
+    pub fn num_items(&self) -> usize {
+        self.colors.len()
+    }
+
+    /// The colour of the given item.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `item` is not smaller than the number of items.
+    pub fn color_of(&self, item: usize) -> usize {
+        self.colors[item]
+    }
+
+    /// The items of the given colour, in increasing order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `color` is not smaller than the number of colours.
+    pub fn class(&self, color: usize) -> &SortedUnique<Vec<usize>> {
+        &self.classes[color]
+    }
+
+    /// The colour classes, ordered by colour.
+    ///
+    /// Every item belongs to exactly one class, and classes are never empty.
+    pub fn classes(&self) -> &DisjointClasses<SortedUnique<Vec<usize>>> {
+        &self.classes
+    }
+
+    /// Recover the colour classes, ordered by colour.
+    pub fn into_classes(self) -> DisjointClasses<SortedUnique<Vec<usize>>> {
+        self.classes
+    }
+}
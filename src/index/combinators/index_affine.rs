@@ -0,0 +1,226 @@
+use crate::index::{IndexList, UniqueIndexList};
+use crate::internal::Sealed;
+use paradis_core::Bounds;
+use std::fmt::Debug;
+
+/// An index combinator that maps each index `i` to `scale * i + offset`.
+///
+/// See [IndexList::index_affine](crate::index::IndexList::index_affine) and
+/// [IndexList::index_offset](crate::index::IndexList::index_offset).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexAffine<I: IndexList>
+where
+    I::Index: Affine,
+{
+    indices: I,
+    scale: <I::Index as Affine>::Scale,
+    offset: <I::Index as Affine>::Offset,
+    bounds: Option<Bounds<I::Index>>,
+}
+
+impl<I> IndexAffine<I>
+where
+    I: IndexList,
+    I::Index: Affine,
+{
+    /// Map each index `i` in the list to `scale * i + offset`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the scale is zero along any axis, or if the bounds of the index list
+    /// can not be mapped without overflow.
+    pub fn new(
+        indices: I,
+        scale: <I::Index as Affine>::Scale,
+        offset: <I::Index as Affine>::Offset,
+    ) -> Self {
+        assert!(
+            I::Index::scale_is_nonzero(scale),
+            "scale must be non-zero along every axis"
+        );
+        let bounds = indices.bounds().map(|bounds| {
+            I::Index::checked_affine_bounds(bounds, scale, offset)
+                .expect("mapped index bounds must not overflow")
+        });
+        Self {
+            indices,
+            scale,
+            offset,
+            bounds,
+        }
+    }
+}
+
+unsafe impl<I> IndexList for IndexAffine<I>
+where
+    I: IndexList,
+    I::Index: Affine,
+{
+    type Index = I::Index;
+
+    const ALWAYS_BOUNDED: bool = I::ALWAYS_BOUNDED;
+
+    #[inline]
+    unsafe fn get_index_unchecked(&self, loc: usize) -> Self::Index {
+        let index = unsafe { self.indices.get_index_unchecked(loc) };
+        if self.bounds.is_some() {
+            // The mapped bounds did not overflow, and so no index inside them does
+            index.wrapping_affine(self.scale, self.offset)
+        } else {
+            index
+                .checked_affine(self.scale, self.offset)
+                .expect("mapped index must not overflow")
+        }
+    }
+
+    #[inline]
+    fn num_indices(&self) -> usize {
+        self.indices.num_indices()
+    }
+
+    fn bounds(&self) -> Option<Bounds<Self::Index>> {
+        self.bounds
+    }
+}
+
+/// An affine map with non-zero scale is injective, and therefore preserves uniqueness.
+unsafe impl<I> UniqueIndexList for IndexAffine<I>
+where
+    I: UniqueIndexList,
+    I::Index: Affine,
+{
+}
+
+/// Indices that can be mapped by an affine map `scale * i + offset`, separately for each axis.
+///
+/// This is part of the machinery that drives
+/// [IndexList::index_affine](crate::index::IndexList::index_affine).
+pub trait Affine: Sealed + Copy + Send + Sync {
+    /// The scale of the map, such as `usize` or `(usize, usize)`.
+    type Scale: Copy + Debug + PartialEq + Eq + Send + Sync;
+    /// The (signed) offset of the map, such as `isize` or `(isize, isize)`.
+    type Offset: Copy + Debug + PartialEq + Eq + Send + Sync;
+
+    /// A scale of one along every axis.
+    fn unit_scale() -> Self::Scale;
+
+    /// Determine if the scale is non-zero along every axis.
+    fn scale_is_nonzero(scale: Self::Scale) -> bool;
+
+    /// Map this index, returning `None` on overflow.
+    fn checked_affine(self, scale: Self::Scale, offset: Self::Offset) -> Option<Self>;
+
+    /// Map this index, wrapping around on overflow.
+    fn wrapping_affine(self, scale: Self::Scale, offset: Self::Offset) -> Self;
+
+    /// Map the given bounds to the tightest bounds containing all mapped indices,
+    /// returning `None` on overflow.
+    fn checked_affine_bounds(
+        bounds: Bounds<Self>,
+        scale: Self::Scale,
+        offset: Self::Offset,
+    ) -> Option<Bounds<Self>>;
+}
+
+impl Affine for usize {
+    type Scale = usize;
+    type Offset = isize;
+
+    #[inline(always)]
+    fn unit_scale() -> usize {
+        1
+    }
+
+    #[inline(always)]
+    fn scale_is_nonzero(scale: usize) -> bool {
+        scale != 0
+    }
+
+    #[inline(always)]
+    fn checked_affine(self, scale: usize, offset: isize) -> Option<Self> {
+        self.checked_mul(scale)?.checked_add_signed(offset)
+    }
+
+    #[inline(always)]
+    fn wrapping_affine(self, scale: usize, offset: isize) -> Self {
+        self.wrapping_mul(scale).wrapping_add_signed(offset)
+    }
+
+    fn checked_affine_bounds(
+        bounds: Bounds<Self>,
+        scale: usize,
+        offset: isize,
+    ) -> Option<Bounds<Self>> {
+        if bounds.is_empty() {
+            return Some(Bounds::new_empty());
+        }
+        let new_offset = bounds.offset.checked_affine(scale, offset)?;
+        let last = (bounds.offset + (bounds.extent - 1)).checked_affine(scale, offset)?;
+        Some(Bounds {
+            offset: new_offset,
+            extent: (last - new_offset).checked_add(1)?,
+        })
+    }
+}
+
+macro_rules! replace_with {
+    ($content:tt, $replacement:tt) => {
+        $replacement
+    };
+}
+
+macro_rules! impl_tuple_affine {
+    ($($idx:tt),*) => {
+        impl Affine for ($(replace_with!($idx, usize)),*) {
+            type Scale = ($(replace_with!($idx, usize)),*);
+            type Offset = ($(replace_with!($idx, isize)),*);
+
+            #[inline(always)]
+            fn unit_scale() -> Self::Scale {
+                ($(replace_with!($idx, 1)),*)
+            }
+
+            #[inline(always)]
+            fn scale_is_nonzero(scale: Self::Scale) -> bool {
+                true $(&& usize::scale_is_nonzero(scale.$idx))*
+            }
+
+            #[inline(always)]
+            fn checked_affine(self, scale: Self::Scale, offset: Self::Offset) -> Option<Self> {
+                Some(($(self.$idx.checked_affine(scale.$idx, offset.$idx)?),*))
+            }
+
+            #[inline(always)]
+            fn wrapping_affine(self, scale: Self::Scale, offset: Self::Offset) -> Self {
+                ($(self.$idx.wrapping_affine(scale.$idx, offset.$idx)),*)
+            }
+
+            fn checked_affine_bounds(
+                bounds: Bounds<Self>,
+                scale: Self::Scale,
+                offset: Self::Offset,
+            ) -> Option<Bounds<Self>> {
+                if bounds.is_empty() {
+                    return Some(Bounds::new_empty());
+                }
+                // Bounds are mapped separately along each axis
+                let bounds_1d = ($(
+                    usize::checked_affine_bounds(
+                        Bounds { offset: bounds.offset.$idx, extent: bounds.extent.$idx },
+                        scale.$idx,
+                        offset.$idx,
+                    )?
+                ),*);
+                Some(Bounds {
+                    offset: ($(bounds_1d.$idx.offset),*),
+                    extent: ($(bounds_1d.$idx.extent),*),
+                })
+            }
+        }
+    };
+}
+
+impl_tuple_affine!(0, 1);
+impl_tuple_affine!(0, 1, 2);
+impl_tuple_affine!(0, 1, 2, 3);
+impl_tuple_affine!(0, 1, 2, 3, 4);
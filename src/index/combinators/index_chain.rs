@@ -0,0 +1,82 @@
+use crate::error::NonUniqueIndex;
+use crate::index::{CheckedUnique, IndexList, UniqueIndexList};
+use crate::RecordIndex;
+use paradis_core::Bounds;
+
+/// The concatenation of two index lists.
+///
+/// See [IndexList::index_chain](crate::index::IndexList::index_chain) for more
+/// information.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexChain<A, B>(A, B);
+
+impl<A, B> IndexChain<A, B>
+where
+    A: IndexList,
+    B: IndexList<Index = A::Index>,
+{
+    /// Concatenate two index lists.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the total number of indices does not fit in `usize`.
+    pub fn new(a: A, b: B) -> Self {
+        assert!(
+            a.num_indices().checked_add(b.num_indices()).is_some(),
+            "IndexChain requires the total number of indices to fit in usize"
+        );
+        Self(a, b)
+    }
+}
+
+impl<A, B> IndexChain<A, B>
+where
+    A: UniqueIndexList,
+    B: UniqueIndexList<Index = A::Index>,
+    A::Index: RecordIndex,
+{
+    /// Checks that the two chained lists are disjoint, so that the concatenation is unique.
+    ///
+    /// If the bounds of the two lists do not overlap, the lists are disjoint and this check
+    /// is `O(1)`. Otherwise, all indices in the concatenation are checked.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the two lists have an index in common.
+    pub fn check_disjoint(self) -> Result<CheckedUnique<Self>, NonUniqueIndex<A::Index>> {
+        let bounds_a = self.0.bounds();
+        let bounds_b = self.1.bounds();
+        CheckedUnique::from_unique_parts(self, bounds_a, bounds_b)
+    }
+}
+
+unsafe impl<A, B> IndexList for IndexChain<A, B>
+where
+    A: IndexList,
+    B: IndexList<Index = A::Index>,
+    A::Index: RecordIndex,
+{
+    type Index = A::Index;
+
+    const ALWAYS_BOUNDED: bool = A::ALWAYS_BOUNDED && B::ALWAYS_BOUNDED;
+
+    unsafe fn get_index_unchecked(&self, loc: usize) -> Self::Index {
+        let n_a = self.0.num_indices();
+        if loc < n_a {
+            unsafe { self.0.get_index_unchecked(loc) }
+        } else {
+            unsafe { self.1.get_index_unchecked(loc - n_a) }
+        }
+    }
+
+    fn num_indices(&self) -> usize {
+        self.0.num_indices() + self.1.num_indices()
+    }
+
+    fn bounds(&self) -> Option<Bounds<Self::Index>> {
+        self.0.bounds().zip(self.1.bounds()).map(|(mut a, b)| {
+            a.enclose_bounds(&b);
+            a
+        })
+    }
+}
@@ -0,0 +1,91 @@
+use crate::error::NonUniqueIndex;
+use crate::index::{CheckedUnique, IndexList, UniqueIndexList};
+use crate::RecordIndex;
+use paradis_core::Bounds;
+
+/// Two index lists with alternating indices.
+///
+/// See [IndexList::index_interleave](crate::index::IndexList::index_interleave) for more
+/// information.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexInterleave<A, B>(A, B);
+
+impl<A, B> IndexInterleave<A, B>
+where
+    A: IndexList,
+    B: IndexList<Index = A::Index>,
+{
+    /// Interleave two index lists.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the total number of indices does not fit in `usize`.
+    pub fn new(a: A, b: B) -> Self {
+        assert!(
+            a.num_indices().checked_add(b.num_indices()).is_some(),
+            "IndexInterleave requires the total number of indices to fit in usize"
+        );
+        Self(a, b)
+    }
+}
+
+impl<A, B> IndexInterleave<A, B>
+where
+    A: UniqueIndexList,
+    B: UniqueIndexList<Index = A::Index>,
+    A::Index: RecordIndex,
+{
+    /// Checks that the two interleaved lists are disjoint, so that the result is unique.
+    ///
+    /// If the bounds of the two lists do not overlap, the lists are disjoint and this check
+    /// is `O(1)`. Otherwise, all indices in the interleaved list are checked.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the two lists have an index in common.
+    pub fn check_disjoint(self) -> Result<CheckedUnique<Self>, NonUniqueIndex<A::Index>> {
+        let bounds_a = self.0.bounds();
+        let bounds_b = self.1.bounds();
+        CheckedUnique::from_unique_parts(self, bounds_a, bounds_b)
+    }
+}
+
+unsafe impl<A, B> IndexList for IndexInterleave<A, B>
+where
+    A: IndexList,
+    B: IndexList<Index = A::Index>,
+    A::Index: RecordIndex,
+{
+    type Index = A::Index;
+
+    const ALWAYS_BOUNDED: bool = A::ALWAYS_BOUNDED && B::ALWAYS_BOUNDED;
+
+    unsafe fn get_index_unchecked(&self, loc: usize) -> Self::Index {
+        let n_a = self.0.num_indices();
+        let n_b = self.1.num_indices();
+        let n_common = usize::min(n_a, n_b);
+        if loc < 2 * n_common {
+            if loc % 2 == 1 {
+                unsafe { self.1.get_index_unchecked(loc / 2) }
+            } else {
+                unsafe { self.0.get_index_unchecked(loc / 2) }
+            }
+        } else if n_a > n_b {
+            // The remaining indices of the longer list follow the interleaved part
+            unsafe { self.0.get_index_unchecked(loc - n_common) }
+        } else {
+            unsafe { self.1.get_index_unchecked(loc - n_common) }
+        }
+    }
+
+    fn num_indices(&self) -> usize {
+        self.0.num_indices() + self.1.num_indices()
+    }
+
+    fn bounds(&self) -> Option<Bounds<Self::Index>> {
+        self.0.bounds().zip(self.1.bounds()).map(|(mut a, b)| {
+            a.enclose_bounds(&b);
+            a
+        })
+    }
+}
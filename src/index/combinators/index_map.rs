@@ -0,0 +1,108 @@
+use crate::index::{IndexList, UniqueIndexList};
+use paradis_core::Bounds;
+use std::fmt::{Debug, Formatter};
+
+/// An index combinator that maps indices with a function.
+///
+/// See [IndexList::index_map](crate::index::IndexList::index_map).
+#[derive(Clone)]
+pub struct IndexMap<I, F, J, const INJECTIVE: bool = false> {
+    indices: I,
+    f: F,
+    bounds: Option<Bounds<J>>,
+}
+
+impl<I, F, J> IndexMap<I, F, J>
+where
+    I: IndexList,
+    F: Fn(I::Index) -> J,
+{
+    /// Map each index in the list with the provided function.
+    ///
+    /// # Safety
+    ///
+    /// The function **must** be deterministic, see [`IndexList::index_map`].
+    pub unsafe fn new(indices: I, f: F) -> Self {
+        Self {
+            indices,
+            f,
+            bounds: None,
+        }
+    }
+
+    /// Promise that the function is injective, so that the result is unique
+    /// whenever the underlying index list is unique.
+    ///
+    /// # Safety
+    ///
+    /// The function **must** map distinct indices in the underlying list to distinct indices.
+    pub unsafe fn assume_injective(self) -> IndexMap<I, F, J, true> {
+        IndexMap {
+            indices: self.indices,
+            f: self.f,
+            bounds: self.bounds,
+        }
+    }
+}
+
+impl<I, F, J, const INJECTIVE: bool> IndexMap<I, F, J, INJECTIVE> {
+    /// Provide bounds for the mapped indices.
+    ///
+    /// Bounds allow [`narrow_access`](crate::index::narrow_access) to check all indices
+    /// up front, so that no bounds checks are necessary upon access.
+    ///
+    /// # Safety
+    ///
+    /// Every mapped index **must** be contained in the provided bounds.
+    pub unsafe fn assume_bounds(self, bounds: Bounds<J>) -> Self {
+        Self {
+            bounds: Some(bounds),
+            ..self
+        }
+    }
+}
+
+impl<I: Debug, F, J: Debug, const INJECTIVE: bool> Debug for IndexMap<I, F, J, INJECTIVE> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IndexMap")
+            .field("indices", &self.indices)
+            .field("bounds", &self.bounds)
+            .field("injective", &INJECTIVE)
+            .finish_non_exhaustive()
+    }
+}
+
+unsafe impl<I, F, J, const INJECTIVE: bool> IndexList for IndexMap<I, F, J, INJECTIVE>
+where
+    I: IndexList,
+    F: Fn(I::Index) -> J + Send + Sync,
+    J: Copy + Send + Sync,
+{
+    type Index = J;
+
+    const ALWAYS_BOUNDED: bool = false;
+
+    #[inline]
+    unsafe fn get_index_unchecked(&self, loc: usize) -> J {
+        (self.f)(unsafe { self.indices.get_index_unchecked(loc) })
+    }
+
+    #[inline]
+    fn num_indices(&self) -> usize {
+        self.indices.num_indices()
+    }
+
+    fn bounds(&self) -> Option<Bounds<J>> {
+        self.bounds
+    }
+}
+
+/// This is sound because the only way to obtain an injective [`IndexMap`] is through
+/// an unsafe method where the user promises that the function is injective.
+unsafe impl<I, F, J> UniqueIndexList for IndexMap<I, F, J, true>
+where
+    I: UniqueIndexList,
+    F: Fn(I::Index) -> J + Send + Sync,
+    J: Copy + Send + Sync,
+{
+}
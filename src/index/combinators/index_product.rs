@@ -1,15 +1,41 @@
+use crate::error::Overflow;
 use crate::index::{IndexList, UniqueIndexList};
 use paradis_core::Bounds;
 
 /// A Cartesian product of index sets.
 ///
 /// TODO: Example, document row-major behavior etc.
-/// TODO: Also provide `IndexRProduct` for alternative column-major ordering
-///       that's probably a bad name, since it's not "reverse" as "R" might suggest.
-///       IndexCProduct? Not sure..
+///
+/// See [`index_product_n`](crate::index::index_product_n) for products of more than two
+/// lists, and for column-major order.
+///
+/// # Panics
+///
+/// The fields are public, so a product can be constructed directly without checking that its
+/// number of indices fits in `usize`. For such a product, [`num_indices`](IndexList::num_indices)
+/// panics, and so does any operation that relies on it.
+/// Use [`try_new`](Self::try_new) to check this up front.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct IndexProduct<A, B>(pub A, pub B);
 
+impl<A, B> IndexProduct<A, B>
+where
+    A: IndexList,
+    B: IndexList,
+{
+    /// Form the Cartesian product of two index lists, if its number of indices fits in `usize`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the number of indices in the product does not fit in `usize`.
+    pub fn try_new(a: A, b: B) -> Result<Self, Overflow> {
+        match a.num_indices().checked_mul(b.num_indices()) {
+            Some(_) => Ok(Self(a, b)),
+            None => Err(Overflow),
+        }
+    }
+}
+
 unsafe impl<A, B> IndexList for IndexProduct<A, B>
 where
     A: IndexList,
@@ -26,7 +52,10 @@ where
     }
 
     fn num_indices(&self) -> usize {
-        self.0.num_indices() * self.1.num_indices()
+        self.0
+            .num_indices()
+            .checked_mul(self.1.num_indices())
+            .expect("number of indices must fit in usize")
     }
 
     fn bounds(&self) -> Option<Bounds<Self::Index>> {
@@ -0,0 +1,170 @@
+use crate::error::Overflow;
+use crate::index::{IndexList, IndexOrder, RowMajor, UniqueIndexList, Unravel};
+use crate::internal::Sealed;
+use paradis_core::Bounds;
+
+/// A Cartesian product of any number of index lists, visited in the given order.
+///
+/// See [`index_product_n`](crate::index::index_product_n).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexProductN<Lists: IndexLists, Order = RowMajor> {
+    lists: Lists,
+    extents: Lists::Extents,
+    num_indices: usize,
+    order: Order,
+}
+
+impl<Lists: IndexLists, Order: IndexOrder> IndexProductN<Lists, Order> {
+    /// Form the Cartesian product of a tuple of index lists, visited in the given order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of indices in the product does not fit in `usize`.
+    pub fn new(lists: Lists, order: Order) -> Self {
+        Self::try_new(lists, order).expect("number of indices must fit in usize")
+    }
+
+    /// Form the Cartesian product of a tuple of index lists, if its number of indices
+    /// fits in `usize`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the number of indices in the product does not fit in `usize`.
+    pub fn try_new(lists: Lists, order: Order) -> Result<Self, Overflow> {
+        let extents = lists.extents();
+        let num_indices = Lists::Extents::checked_volume(extents).ok_or(Overflow)?;
+        Ok(Self {
+            lists,
+            extents,
+            num_indices,
+            order,
+        })
+    }
+
+    /// Obtain a reference to the underlying tuple of index lists.
+    pub fn get_inner(&self) -> &Lists {
+        &self.lists
+    }
+
+    /// The order in which indices are visited.
+    pub fn order(&self) -> Order {
+        self.order
+    }
+}
+
+unsafe impl<Lists, Order> IndexList for IndexProductN<Lists, Order>
+where
+    Lists: IndexLists,
+    Order: IndexOrder,
+{
+    type Index = Lists::Index;
+
+    const ALWAYS_BOUNDED: bool = Lists::ALWAYS_BOUNDED;
+
+    #[inline]
+    unsafe fn get_index_unchecked(&self, loc: usize) -> Self::Index {
+        let locs = Order::unravel(loc, self.extents);
+        // SAFETY: loc is smaller than the volume of the extents, so every unravelled
+        // location is smaller than the number of indices in the corresponding list
+        unsafe { self.lists.get_indices_unchecked(locs) }
+    }
+
+    #[inline]
+    fn num_indices(&self) -> usize {
+        self.num_indices
+    }
+
+    fn bounds(&self) -> Option<Bounds<Self::Index>> {
+        self.lists.bounds()
+    }
+}
+
+unsafe impl<Lists, Order> UniqueIndexList for IndexProductN<Lists, Order>
+where
+    Lists: UniqueIndexLists,
+    Order: IndexOrder,
+{
+}
+
+/// Tuples of index lists.
+///
+/// This is part of the machinery that drives
+/// [`index_product_n`](crate::index::index_product_n).
+/// The trait is *sealed*, and implemented for tuples of two to five index lists.
+///
+/// # Safety
+///
+/// [`get_indices_unchecked`](Self::get_indices_unchecked) must return the tuple of indices
+/// at the given locations in each list, and [`bounds`](Self::bounds) must combine the bounds
+/// of each list.
+pub unsafe trait IndexLists: Sealed + Send + Sync {
+    /// The tuple of the index types of the lists.
+    type Index: Copy;
+    /// The tuple of the number of indices in each list.
+    type Extents: Unravel + Copy + Send + Sync + std::fmt::Debug + PartialEq + Eq;
+
+    /// Whether every list is always bounded.
+    const ALWAYS_BOUNDED: bool;
+
+    /// The number of indices in each list.
+    fn extents(&self) -> Self::Extents;
+
+    /// Obtain the tuple of indices at the given locations in each list.
+    ///
+    /// # Safety
+    ///
+    /// Each location must be in bounds with respect to the corresponding list.
+    unsafe fn get_indices_unchecked(&self, locs: Self::Extents) -> Self::Index;
+
+    /// The bounds of the product, if every list is bounded.
+    fn bounds(&self) -> Option<Bounds<Self::Index>>;
+}
+
+/// Tuples of *unique* index lists, whose Cartesian product is unique.
+///
+/// # Safety
+///
+/// Every list in the tuple must be unique.
+pub unsafe trait UniqueIndexLists: IndexLists {}
+
+macro_rules! replace_with_usize {
+    ($content:tt) => {
+        usize
+    };
+}
+
+macro_rules! impl_index_lists {
+    ($($list:ident: $idx:tt),*) => {
+        unsafe impl<$($list: IndexList),*> IndexLists for ($($list),*) {
+            type Index = ($($list::Index),*);
+            type Extents = ($(replace_with_usize!($idx)),*);
+
+            const ALWAYS_BOUNDED: bool = true $(&& $list::ALWAYS_BOUNDED)*;
+
+            #[inline]
+            fn extents(&self) -> Self::Extents {
+                ($(self.$idx.num_indices()),*)
+            }
+
+            #[inline]
+            unsafe fn get_indices_unchecked(&self, locs: Self::Extents) -> Self::Index {
+                unsafe { ($(self.$idx.get_index_unchecked(locs.$idx)),*) }
+            }
+
+            fn bounds(&self) -> Option<Bounds<Self::Index>> {
+                let bounds = ($(self.$idx.bounds()?),*);
+                Some(Bounds {
+                    offset: ($(bounds.$idx.offset),*),
+                    extent: ($(bounds.$idx.extent),*),
+                })
+            }
+        }
+
+        unsafe impl<$($list: UniqueIndexList),*> UniqueIndexLists for ($($list),*) {}
+    };
+}
+
+impl_index_lists!(A: 0, B: 1);
+impl_index_lists!(A: 0, B: 1, C: 2);
+impl_index_lists!(A: 0, B: 1, C: 2, D: 3);
+impl_index_lists!(A: 0, B: 1, C: 2, D: 3, E: 4);
@@ -0,0 +1,31 @@
+use crate::index::{IndexList, UniqueIndexList};
+use paradis_core::Bounds;
+
+/// An index list in reverse order.
+///
+/// See [IndexList::index_rev](crate::index::IndexList::index_rev).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexRev<I>(pub(crate) I);
+
+unsafe impl<I: IndexList> IndexList for IndexRev<I> {
+    type Index = I::Index;
+
+    const ALWAYS_BOUNDED: bool = I::ALWAYS_BOUNDED;
+
+    #[inline]
+    unsafe fn get_index_unchecked(&self, loc: usize) -> Self::Index {
+        let n = self.0.num_indices();
+        unsafe { self.0.get_index_unchecked(n - 1 - loc) }
+    }
+
+    #[inline]
+    fn num_indices(&self) -> usize {
+        self.0.num_indices()
+    }
+
+    fn bounds(&self) -> Option<Bounds<Self::Index>> {
+        self.0.bounds()
+    }
+}
+
+unsafe impl<I: UniqueIndexList> UniqueIndexList for IndexRev<I> {}
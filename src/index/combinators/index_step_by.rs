@@ -0,0 +1,53 @@
+use crate::index::{IndexList, UniqueIndexList};
+use paradis_core::Bounds;
+
+/// Every `step`-th index of an index list.
+///
+/// See [IndexList::index_step_by](crate::index::IndexList::index_step_by).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexStepBy<I> {
+    indices: I,
+    step: usize,
+}
+
+impl<I: IndexList> IndexStepBy<I> {
+    /// Select every `step`-th index of an index list, starting with the first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero.
+    pub fn new(indices: I, step: usize) -> Self {
+        assert!(step != 0, "step must be non-zero");
+        Self { indices, step }
+    }
+}
+
+unsafe impl<I: IndexList> IndexList for IndexStepBy<I> {
+    type Index = I::Index;
+
+    const ALWAYS_BOUNDED: bool = I::ALWAYS_BOUNDED;
+
+    #[inline]
+    unsafe fn get_index_unchecked(&self, loc: usize) -> Self::Index {
+        unsafe { self.indices.get_index_unchecked(loc * self.step) }
+    }
+
+    #[inline]
+    fn num_indices(&self) -> usize {
+        self.indices.num_indices().div_ceil(self.step)
+    }
+
+    fn bounds(&self) -> Option<Bounds<Self::Index>> {
+        self.indices.bounds()
+    }
+
+    fn contiguous_bounds(&self) -> Option<Bounds<Self::Index>> {
+        if self.step == 1 {
+            self.indices.contiguous_bounds()
+        } else {
+            None
+        }
+    }
+}
+
+unsafe impl<I: UniqueIndexList> UniqueIndexList for IndexStepBy<I> {}
@@ -0,0 +1,75 @@
+use crate::index::{IndexList, UniqueIndexList};
+use paradis_core::Bounds;
+use std::ops::{Bound, RangeBounds};
+
+/// A contiguous part of an index list.
+///
+/// See [IndexList::index_subrange](crate::index::IndexList::index_subrange),
+/// [IndexList::index_skip](crate::index::IndexList::index_skip) and
+/// [IndexList::index_take](crate::index::IndexList::index_take).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexSubrange<I> {
+    indices: I,
+    start: usize,
+    len: usize,
+}
+
+impl<I: IndexList> IndexSubrange<I> {
+    /// Restrict an index list to the locations in the given range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds with respect to the index list,
+    /// or if the start of the range is greater than its end.
+    pub fn new(indices: I, range: impl RangeBounds<usize>) -> Self {
+        let n = indices.num_indices();
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.checked_add(1).expect("range start must not overflow"),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.checked_add(1).expect("range end must not overflow"),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => n,
+        };
+        assert!(
+            start <= end,
+            "range start must not be greater than range end"
+        );
+        assert!(end <= n, "range must be in bounds of the index list");
+        Self {
+            indices,
+            start,
+            len: end - start,
+        }
+    }
+
+    /// Obtain a reference to the underlying index list.
+    pub fn get_inner(&self) -> &I {
+        &self.indices
+    }
+}
+
+unsafe impl<I: IndexList> IndexList for IndexSubrange<I> {
+    type Index = I::Index;
+
+    const ALWAYS_BOUNDED: bool = I::ALWAYS_BOUNDED;
+
+    #[inline]
+    unsafe fn get_index_unchecked(&self, loc: usize) -> Self::Index {
+        unsafe { self.indices.get_index_unchecked(self.start + loc) }
+    }
+
+    #[inline]
+    fn num_indices(&self) -> usize {
+        self.len
+    }
+
+    fn bounds(&self) -> Option<Bounds<Self::Index>> {
+        // The bounds of the full list also contain every part of it
+        self.indices.bounds()
+    }
+}
+
+unsafe impl<I: UniqueIndexList> UniqueIndexList for IndexSubrange<I> {}
@@ -0,0 +1,94 @@
+use crate::index::{IndexList, UniqueIndexList};
+use paradis_core::Bounds;
+
+/// A Cartesian product of two index lists, visited tile by tile.
+///
+/// See [IndexList::index_tiled_product](crate::index::IndexList::index_tiled_product).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexTiledProduct<A, B> {
+    a: A,
+    b: B,
+    tile_shape: (usize, usize),
+    num_indices: usize,
+}
+
+impl<A, B> IndexTiledProduct<A, B>
+where
+    A: IndexList,
+    B: IndexList,
+{
+    /// Form the Cartesian product of two index lists, visited in tiles of the given shape.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the tile shape is zero along any axis, or if the number of indices in the
+    /// product does not fit in `usize`.
+    pub fn new(a: A, b: B, tile_shape: (usize, usize)) -> Self {
+        assert!(
+            tile_shape.0 > 0 && tile_shape.1 > 0,
+            "tile shape must be non-zero along every axis"
+        );
+        let (m, n) = (a.num_indices(), b.num_indices());
+        let num_indices = m
+            .checked_mul(n)
+            .expect("number of indices must fit in usize");
+        // Tiles larger than the product behave like tiles covering the product. Clamping
+        // ensures that the number of indices in a row of tiles fits in usize
+        let tile_shape = (tile_shape.0.min(m), tile_shape.1.min(n));
+        Self {
+            a,
+            b,
+            tile_shape,
+            num_indices,
+        }
+    }
+}
+
+unsafe impl<A, B> IndexList for IndexTiledProduct<A, B>
+where
+    A: IndexList,
+    B: IndexList,
+{
+    type Index = (A::Index, B::Index);
+
+    const ALWAYS_BOUNDED: bool = A::ALWAYS_BOUNDED && B::ALWAYS_BOUNDED;
+
+    #[inline]
+    unsafe fn get_index_unchecked(&self, loc: usize) -> Self::Index {
+        let n = self.b.num_indices();
+        let m = self.a.num_indices();
+        let (tile_rows, tile_cols) = self.tile_shape;
+
+        // Every row of tiles except the last contains exactly `tile_rows * n` indices
+        let tile_row = loc / (tile_rows * n);
+        let row_start = tile_row * tile_rows;
+        let height = tile_rows.min(m - row_start);
+        let loc = loc - tile_row * tile_rows * n;
+
+        // Every tile in this row of tiles except the last contains `height * tile_cols` indices
+        let tile_col = loc / (height * tile_cols);
+        let col_start = tile_col * tile_cols;
+        let width = tile_cols.min(n - col_start);
+        let loc = loc - tile_col * height * tile_cols;
+
+        let i = row_start + loc / width;
+        let j = col_start + loc % width;
+        unsafe { (self.a.get_index_unchecked(i), self.b.get_index_unchecked(j)) }
+    }
+
+    #[inline]
+    fn num_indices(&self) -> usize {
+        self.num_indices
+    }
+
+    fn bounds(&self) -> Option<Bounds<Self::Index>> {
+        self.a.bounds().zip(self.b.bounds()).map(|(a, b)| a.zip(b))
+    }
+}
+
+unsafe impl<A, B> UniqueIndexList for IndexTiledProduct<A, B>
+where
+    A: UniqueIndexList,
+    B: UniqueIndexList,
+{
+}
@@ -1,3 +1,4 @@
+use crate::error::LengthMismatch;
 use crate::index::{IndexList, UniqueIndexList};
 use paradis_core::Bounds;
 
@@ -26,6 +27,20 @@ where
         );
         Self(a, b)
     }
+
+    /// Zip two index lists, if they have the same number of indices.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the two index lists do not have the same number of indices.
+    pub fn try_new(a: A, b: B) -> Result<Self, LengthMismatch> {
+        let (first, second) = (a.num_indices(), b.num_indices());
+        if first == second {
+            Ok(Self(a, b))
+        } else {
+            Err(LengthMismatch { first, second })
+        }
+    }
 }
 
 // TODO: Test this impl
@@ -94,6 +109,20 @@ where
         );
         Self(a, b)
     }
+
+    /// Zip two index lists, if they have the same number of indices.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the two index lists do not have the same number of indices.
+    pub fn try_new(a: A, b: B) -> Result<Self, LengthMismatch> {
+        let (first, second) = (a.num_indices(), b.num_indices());
+        if first == second {
+            Ok(Self(a, b))
+        } else {
+            Err(LengthMismatch { first, second })
+        }
+    }
 }
 
 // TODO: Test this impl
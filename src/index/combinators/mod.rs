@@ -1,13 +1,31 @@
 //! Combinators used in the construction of unique index sets.
 
+mod index_affine;
 mod index_cast;
+mod index_chain;
 mod index_flatten;
+mod index_interleave;
+mod index_map;
 mod index_product;
+mod index_product_n;
+mod index_rev;
+mod index_step_by;
+mod index_subrange;
+mod index_tiled_product;
 mod index_transpose;
 mod index_zip;
 
+pub use index_affine::{Affine, IndexAffine};
 pub use index_cast::IndexCast;
+pub use index_chain::IndexChain;
 pub use index_flatten::{Concatenate, Concatenated, Flatten, IndexFlatten};
+pub use index_interleave::IndexInterleave;
+pub use index_map::IndexMap;
 pub use index_product::IndexProduct;
+pub use index_product_n::{IndexLists, IndexProductN, UniqueIndexLists};
+pub use index_rev::IndexRev;
+pub use index_step_by::IndexStepBy;
+pub use index_subrange::IndexSubrange;
+pub use index_tiled_product::IndexTiledProduct;
 pub use index_transpose::{IndexTranspose, Transpose};
 pub use index_zip::{IndexAZip, IndexZip};
@@ -0,0 +1,155 @@
+use crate::error::NonUniqueIndex;
+use crate::index::{CheckedUnique, IndexList, UniqueIndexList};
+use crate::RecordIndex;
+use paradis_core::Bounds;
+use std::ops::Deref;
+
+/// Unique index lists that are pairwise disjoint, such as the colour classes of a colouring.
+///
+/// No index occurs in more than one class, so the records at the indices of different classes
+/// are distinct. The classes can therefore be processed one after another, with the records of
+/// each class processed in parallel, without checking for disjointness every time.
+/// [`Checkerboard::colors`](crate::index::patterns::Checkerboard::colors) and
+/// [`Coloring::classes`](crate::index::coloring::Coloring::classes) are disjoint by construction.
+///
+/// Dereferences to a slice of the classes.
+///
+/// # Examples
+///
+/// ```
+/// use paradis::index::DisjointClasses;
+///
+/// let classes = DisjointClasses::from_classes(vec![0 .. 2, 4 .. 7, 2 .. 4]).unwrap();
+/// assert_eq!(classes.len(), 3);
+/// assert_eq!(classes[1], 4 .. 7);
+///
+/// let err = DisjointClasses::from_classes(vec![0 .. 3, 2 .. 5]).unwrap_err();
+/// assert_eq!((err.index, err.first, err.second), (2, 2, 3));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DisjointClasses<Indices> {
+    classes: Vec<Indices>,
+}
+
+impl<Indices> DisjointClasses<Indices> {
+    /// Recover the classes.
+    pub fn into_inner(self) -> Vec<Indices> {
+        self.classes
+    }
+}
+
+impl<Indices> DisjointClasses<Indices>
+where
+    Indices: UniqueIndexList,
+    Indices::Index: RecordIndex,
+{
+    /// Check that no index occurs in more than one of the provided classes.
+    ///
+    /// The classes are checked as a single list, one class after another, with the strategy
+    /// selected by [`CheckedUnique::from_indices`].
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if an index occurs in more than one class. Its positions refer to the
+    /// classes concatenated in order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the total number of indices does not fit in `usize`.
+    pub fn from_classes(classes: Vec<Indices>) -> Result<Self, NonUniqueIndex<Indices::Index>> {
+        if classes.len() > 1 {
+            CheckedUnique::from_indices(Concatenation::new(&classes))?;
+        }
+        Ok(Self { classes })
+    }
+
+    /// Check that no index occurs in more than one of the provided classes, in parallel.
+    ///
+    /// This is the parallel counterpart of [`from_classes`](Self::from_classes), using
+    /// [`CheckedUnique::par_from_indices`].
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if an index occurs in more than one class. Its positions refer to the
+    /// classes concatenated in order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the total number of indices does not fit in `usize`.
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    #[cfg(feature = "rayon")]
+    pub fn par_from_classes(classes: Vec<Indices>) -> Result<Self, NonUniqueIndex<Indices::Index>> {
+        if classes.len() > 1 {
+            CheckedUnique::par_from_indices(Concatenation::new(&classes))?;
+        }
+        Ok(Self { classes })
+    }
+
+    /// Assume that the provided classes are pairwise disjoint, without checking.
+    ///
+    /// # Safety
+    ///
+    /// No index may occur in more than one class.
+    pub unsafe fn from_classes_unchecked(classes: Vec<Indices>) -> Self {
+        Self { classes }
+    }
+}
+
+impl<Indices> Deref for DisjointClasses<Indices> {
+    type Target = [Indices];
+
+    fn deref(&self) -> &[Indices] {
+        &self.classes
+    }
+}
+
+/// The indices of several classes, one class after another.
+struct Concatenation<'a, Indices> {
+    classes: &'a [Indices],
+    // ends[c] is the total number of indices in the classes 0 ..= c
+    ends: Vec<usize>,
+}
+
+impl<'a, Indices: IndexList> Concatenation<'a, Indices> {
+    fn new(classes: &'a [Indices]) -> Self {
+        let ends = classes
+            .iter()
+            .scan(0usize, |end, indices| {
+                *end = end
+                    .checked_add(indices.num_indices())
+                    .expect("total number of indices must fit in usize");
+                Some(*end)
+            })
+            .collect();
+        Self { classes, ends }
+    }
+}
+
+unsafe impl<Indices> IndexList for Concatenation<'_, Indices>
+where
+    Indices: IndexList,
+    Indices::Index: RecordIndex,
+{
+    type Index = Indices::Index;
+
+    const ALWAYS_BOUNDED: bool = false;
+
+    unsafe fn get_index_unchecked(&self, loc: usize) -> Self::Index {
+        let class = self.ends.partition_point(|&end| end <= loc);
+        let start = class.checked_sub(1).map_or(0, |prev| self.ends[prev]);
+        unsafe { self.classes[class].get_index_unchecked(loc - start) }
+    }
+
+    fn num_indices(&self) -> usize {
+        self.ends.last().copied().unwrap_or(0)
+    }
+
+    fn bounds(&self) -> Option<Bounds<Self::Index>> {
+        self.classes
+            .iter()
+            .try_fold(Bounds::new_empty(), |mut bounds, indices| {
+                bounds.enclose_bounds(&indices.bounds()?);
+                Some(bounds)
+            })
+    }
+}
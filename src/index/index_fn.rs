@@ -0,0 +1,133 @@
+use crate::index::{IndexList, UniqueIndexList};
+use paradis_core::Bounds;
+use std::fmt::{Debug, Formatter};
+
+/// An index list whose indices are computed by a function of their location.
+///
+/// The index at location `loc` is `f(loc)`, for `loc` in `0 .. n`. Since an index list must
+/// always report the same indices, construction is unsafe: the function must be deterministic.
+/// The list is not unique, unless the function is promised to be injective through
+/// [`assume_injective`](Self::assume_injective).
+///
+/// # Examples
+///
+/// ```
+/// use paradis::index::{collect_indices, narrow_access, IndexFn};
+/// use paradis::Bounds;
+///
+/// let mut data = vec![0; 10];
+/// // SAFETY: loc -> 3 * loc + 1 is deterministic and injective, and maps 0 .. 3 to indices
+/// // in 1 .. 8
+/// let indices = unsafe {
+///     IndexFn::new(3, |loc| 3 * loc + 1)
+///         .assume_injective()
+///         .assume_bounds(Bounds { offset: 1, extent: 7 })
+/// };
+/// let collected: Vec<_> = collect_indices(&indices);
+/// assert_eq!(collected, vec![1, 4, 7]);
+///
+/// let access = narrow_access(data.as_mut_slice(), &indices).unwrap();
+/// for x in paradis::iter::create_iter(access) {
+///     *x = 1;
+/// }
+/// assert_eq!(data, vec![0, 1, 0, 0, 1, 0, 0, 1, 0, 0]);
+/// ```
+#[derive(Clone)]
+pub struct IndexFn<F, I, const INJECTIVE: bool = false> {
+    num_indices: usize,
+    f: F,
+    bounds: Option<Bounds<I>>,
+}
+
+impl<F, I> IndexFn<F, I>
+where
+    F: Fn(usize) -> I,
+{
+    /// Construct an index list of `n` indices, where the index at location `loc` is `f(loc)`.
+    ///
+    /// # Safety
+    ///
+    /// The function **must** be deterministic on `0 .. n`: every call with the same location
+    /// must return the same index, for as long as the list exists. Functions that depend on
+    /// mutable state, for example through interior mutability, must not be used.
+    pub unsafe fn new(n: usize, f: F) -> Self {
+        Self {
+            num_indices: n,
+            f,
+            bounds: None,
+        }
+    }
+
+    /// Promise that the function is injective on `0 .. n`, so that the indices are unique.
+    ///
+    /// # Safety
+    ///
+    /// The function **must** map distinct locations in `0 .. n` to distinct indices.
+    pub unsafe fn assume_injective(self) -> IndexFn<F, I, true> {
+        IndexFn {
+            num_indices: self.num_indices,
+            f: self.f,
+            bounds: self.bounds,
+        }
+    }
+}
+
+impl<F, I, const INJECTIVE: bool> IndexFn<F, I, INJECTIVE> {
+    /// Provide bounds for the indices in this list.
+    ///
+    /// Bounds allow [`narrow_access`](crate::index::narrow_access) to check all indices
+    /// up front, so that no bounds checks are necessary upon access.
+    ///
+    /// # Safety
+    ///
+    /// Every index in the list **must** be contained in the provided bounds.
+    pub unsafe fn assume_bounds(self, bounds: Bounds<I>) -> Self {
+        Self {
+            bounds: Some(bounds),
+            ..self
+        }
+    }
+}
+
+impl<F, I: Debug, const INJECTIVE: bool> Debug for IndexFn<F, I, INJECTIVE> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IndexFn")
+            .field("num_indices", &self.num_indices)
+            .field("bounds", &self.bounds)
+            .field("injective", &INJECTIVE)
+            .finish_non_exhaustive()
+    }
+}
+
+unsafe impl<F, I, const INJECTIVE: bool> IndexList for IndexFn<F, I, INJECTIVE>
+where
+    F: Fn(usize) -> I + Send + Sync,
+    I: Copy + Send + Sync,
+{
+    type Index = I;
+
+    const ALWAYS_BOUNDED: bool = false;
+
+    #[inline]
+    unsafe fn get_index_unchecked(&self, loc: usize) -> I {
+        (self.f)(loc)
+    }
+
+    #[inline]
+    fn num_indices(&self) -> usize {
+        self.num_indices
+    }
+
+    fn bounds(&self) -> Option<Bounds<I>> {
+        self.bounds
+    }
+}
+
+/// This is sound because the only way to obtain an injective [`IndexFn`] is through
+/// an unsafe method where the user promises that the function is injective.
+unsafe impl<F, I> UniqueIndexList for IndexFn<F, I, true>
+where
+    F: Fn(usize) -> I + Send + Sync,
+    I: Copy + Send + Sync,
+{
+}
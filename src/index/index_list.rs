@@ -1,9 +1,12 @@
-use crate::error::NonUniqueIndex;
+use crate::error::{LengthMismatch, NonUniqueIndex, NotStrictlyIncreasing, Overflow};
 use crate::index::combinators::{
-    IndexAZip, IndexCast, IndexFlatten, IndexProduct, IndexTranspose, IndexZip,
+    Affine, IndexAZip, IndexAffine, IndexCast, IndexChain, IndexFlatten, IndexInterleave, IndexMap,
+    IndexProduct, IndexRev, IndexStepBy, IndexSubrange, IndexTiledProduct, IndexTranspose,
+    IndexZip,
 };
-use crate::index::{AssumedUnique, CheckedUnique};
+use crate::index::{AssumedUnique, CheckedUnique, SortedUnique};
 use crate::{Bounds, IndexFrom, RecordIndex};
+use std::ops::RangeBounds;
 
 /// A finite list of indices.
 ///
@@ -25,6 +28,9 @@ use crate::{Bounds, IndexFrom, RecordIndex};
 /// be contained inside the reported bounds.
 ///
 /// If `ALWAYS_BOUNDED` is `true`, then [`bounds`](Self::bounds) must never return `None`.
+///
+/// Any bounds returned by [`contiguous_bounds`](Self::contiguous_bounds) *must* contain exactly
+/// the indices in the list, in the same row-major order.
 pub unsafe trait IndexList: Sync + Send {
     /// The index type contained in this index list.
     type Index: Copy;
@@ -50,6 +56,17 @@ pub unsafe trait IndexList: Sync + Send {
     /// Return the tightest bounds that contain all indices in this index list, if possible.
     fn bounds(&self) -> Option<Bounds<Self::Index>>;
 
+    /// Return bounds that this index list enumerates completely in row-major order, if known.
+    ///
+    /// If bounds are returned, the index at location `loc` is the index at linear offset `loc`
+    /// in the bounds (see [`Bounds::index_at_linear_offset`]), so that consumers may compute
+    /// indices directly instead of looking them up in the list. This holds for ranges, for
+    /// example. The default implementation returns `None`.
+    #[inline]
+    fn contiguous_bounds(&self) -> Option<Bounds<Self::Index>> {
+        None
+    }
+
     /// Returns the index at the given location.
     ///
     /// # Panics
@@ -77,6 +94,11 @@ pub unsafe trait IndexList: Sync + Send {
     }
 
     /// Returns the Cartesian product of this index set with another set of (unique) indices.
+    ///
+    /// # Panics
+    ///
+    /// Accessing the product panics if its number of indices does not fit in `usize`.
+    /// Use [`try_index_product`](Self::try_index_product) to check this up front.
     fn index_product<I: IndexList>(self, other: I) -> IndexProduct<Self, I>
     where
         Self: Sized,
@@ -84,6 +106,60 @@ pub unsafe trait IndexList: Sync + Send {
         IndexProduct(self, other)
     }
 
+    /// Returns the Cartesian product of this index set with another, visited tile by tile.
+    ///
+    /// The product is divided into tiles of shape `tile_shape`, which are visited in row-major
+    /// order. The indices in each tile are also visited in row-major order. Tiles at the end of
+    /// each row or column of tiles are smaller if the tile shape does not evenly divide the
+    /// product. This improves cache locality when accessing a column-major collection, or more
+    /// generally when both indices vary over a large range.
+    ///
+    /// The result contains the same indices as [`index_product`](Self::index_product), and
+    /// has the same bounds. It is unique if both lists are unique.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use paradis::index::{collect_indices, IndexList};
+    ///
+    /// let indices: Vec<_> = collect_indices((0 .. 3).index_tiled_product(0 .. 3, (2, 2)));
+    /// assert_eq!(indices, vec![
+    ///     (0, 0), (0, 1), (1, 0), (1, 1), // Tile (0, 0)
+    ///     (0, 2), (1, 2),                 // Tile (0, 1)
+    ///     (2, 0), (2, 1),                 // Tile (1, 0)
+    ///     (2, 2),                         // Tile (1, 1)
+    /// ]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the tile shape is zero along any axis, or if the number of indices in the
+    /// product does not fit in `usize`.
+    fn index_tiled_product<I: IndexList>(
+        self,
+        other: I,
+        tile_shape: (usize, usize),
+    ) -> IndexTiledProduct<Self, I>
+    where
+        Self: Sized,
+    {
+        IndexTiledProduct::new(self, other, tile_shape)
+    }
+
+    /// Returns the Cartesian product of this index set with another, if its number of indices
+    /// fits in `usize`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Overflow`] error if the number of indices in the product does not fit
+    /// in `usize`.
+    fn try_index_product<I: IndexList>(self, other: I) -> Result<IndexProduct<Self, I>, Overflow>
+    where
+        Self: Sized,
+    {
+        IndexProduct::try_new(self, other)
+    }
+
     /// Zips this index list with another.
     ///
     /// Specifically, if `a` and `b` are lists, then the elements of `a.index_zip(b)`
@@ -108,6 +184,21 @@ pub unsafe trait IndexList: Sync + Send {
         IndexZip::new(self, other)
     }
 
+    /// Zips this index list with another, if they have the same number of indices.
+    ///
+    /// This is the fallible counterpart of [`index_zip`](Self::index_zip).
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`LengthMismatch`] error if the other index list does not have the same
+    /// number of indices as this index list.
+    fn try_index_zip<I: IndexList>(self, other: I) -> Result<IndexZip<Self, I>, LengthMismatch>
+    where
+        Self: Sized,
+    {
+        IndexZip::try_new(self, other)
+    }
+
     /// Zips this index list with another, but uniqueness is determined by the second list.
     ///
     /// This is identical to [`index_zip`](Self::index_zip), except that indices are considered
@@ -125,6 +216,238 @@ pub unsafe trait IndexList: Sync + Send {
         IndexAZip::new(self, other)
     }
 
+    /// Zips this index list with another, if they have the same number of indices.
+    ///
+    /// This is the fallible counterpart of [`index_azip`](Self::index_azip).
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`LengthMismatch`] error if the other index list does not have the same
+    /// number of indices as this index list.
+    fn try_index_azip<I: IndexList>(self, other: I) -> Result<IndexAZip<Self, I>, LengthMismatch>
+    where
+        Self: Sized,
+    {
+        IndexAZip::try_new(self, other)
+    }
+
+    /// Concatenates this index list with another.
+    ///
+    /// Specifically, if `a` and `b` are lists, then the elements of `a.index_chain(b)`
+    /// are `a[0], a[1], ..., b[0], b[1], ...`.
+    ///
+    /// # Uniqueness
+    ///
+    /// The concatenation is not in general unique, even if both lists are. If both lists
+    /// are unique, use [`IndexChain::check_disjoint`] to obtain a unique index list.
+    /// This check is cheap if the bounds of the two lists do not overlap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use paradis::index::{collect_indices, IndexList};
+    ///
+    /// let indices = (0 .. 2).index_chain(5 .. 7).check_disjoint().unwrap();
+    /// let collected: Vec<_> = collect_indices(&indices);
+    /// assert_eq!(collected, vec![0, 1, 5, 6]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the total number of indices does not fit in `usize`.
+    fn index_chain<I>(self, other: I) -> IndexChain<Self, I>
+    where
+        Self: Sized,
+        I: IndexList<Index = Self::Index>,
+    {
+        IndexChain::new(self, other)
+    }
+
+    /// Interleaves this index list with another.
+    ///
+    /// Specifically, if `a` and `b` are lists, then the elements of `a.index_interleave(b)`
+    /// are `a[0], b[0], a[1], b[1], ...`. If one list is longer than the other, its
+    /// remaining indices follow at the end.
+    ///
+    /// # Uniqueness
+    ///
+    /// As with [`index_chain`](Self::index_chain), use
+    /// [`IndexInterleave::check_disjoint`] to obtain a unique index list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the total number of indices does not fit in `usize`.
+    fn index_interleave<I>(self, other: I) -> IndexInterleave<Self, I>
+    where
+        Self: Sized,
+        I: IndexList<Index = Self::Index>,
+    {
+        IndexInterleave::new(self, other)
+    }
+
+    /// Restricts this index list to the locations in the given range.
+    ///
+    /// The result is unique if this list is unique, and its bounds are the bounds of this list.
+    /// Since `&I` is an index list whenever `I` is, a (possibly expensive) checked list can be
+    /// reused in pieces.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use paradis::index::{collect_indices, IndexList};
+    ///
+    /// let indices = vec![3usize, 1, 4, 5, 9].check_unique().unwrap();
+    /// let middle: Vec<_> = collect_indices((&indices).index_subrange(1 .. 4));
+    /// assert_eq!(middle, vec![1, 4, 5]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds with respect to the number of indices.
+    fn index_subrange(self, range: impl RangeBounds<usize>) -> IndexSubrange<Self>
+    where
+        Self: Sized,
+    {
+        IndexSubrange::new(self, range)
+    }
+
+    /// Skips the first `n` indices of this index list.
+    ///
+    /// If the list has fewer than `n` indices, the result is empty.
+    /// See [`index_subrange`](Self::index_subrange) for more information.
+    fn index_skip(self, n: usize) -> IndexSubrange<Self>
+    where
+        Self: Sized,
+    {
+        let start = usize::min(n, self.num_indices());
+        IndexSubrange::new(self, start..)
+    }
+
+    /// Takes the first `n` indices of this index list.
+    ///
+    /// If the list has fewer than `n` indices, the result contains all of them.
+    /// See [`index_subrange`](Self::index_subrange) for more information.
+    fn index_take(self, n: usize) -> IndexSubrange<Self>
+    where
+        Self: Sized,
+    {
+        let end = usize::min(n, self.num_indices());
+        IndexSubrange::new(self, ..end)
+    }
+
+    /// Reverses the order of the indices in this index list.
+    ///
+    /// The result is unique if this list is unique.
+    fn index_rev(self) -> IndexRev<Self>
+    where
+        Self: Sized,
+    {
+        IndexRev(self)
+    }
+
+    /// Selects every `step`-th index of this index list, starting with the first.
+    ///
+    /// The result is unique if this list is unique, and its bounds are the bounds of this list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero.
+    fn index_step_by(self, step: usize) -> IndexStepBy<Self>
+    where
+        Self: Sized,
+    {
+        IndexStepBy::new(self, step)
+    }
+
+    /// Shifts every index in this index list by the given (signed) offset.
+    ///
+    /// For tuple indices, the offset is applied separately along each axis.
+    /// The result is unique if this list is unique.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use paradis::index::{collect_indices, IndexList};
+    ///
+    /// let shifted: Vec<_> = collect_indices((1 .. 3).index_product(1 .. 3).index_offset((-1, 2)));
+    /// assert_eq!(shifted, vec![(0, 3), (0, 4), (1, 3), (1, 4)]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bounds of this list can not be shifted without overflow.
+    /// If this list is not bounded, accessing an index that overflows panics.
+    fn index_offset(self, offset: <Self::Index as Affine>::Offset) -> IndexAffine<Self>
+    where
+        Self: Sized,
+        Self::Index: Affine,
+    {
+        IndexAffine::new(self, Self::Index::unit_scale(), offset)
+    }
+
+    /// Maps every index `i` in this index list to `scale * i + offset`.
+    ///
+    /// For tuple indices, the map is applied separately along each axis.
+    /// The result is unique if this list is unique, since the scale must be non-zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use paradis::index::{collect_indices, IndexList};
+    ///
+    /// // Map coarse grid indices to the odd indices of a fine grid
+    /// let fine: Vec<_> = collect_indices((0 .. 3).index_affine(2, 1));
+    /// assert_eq!(fine, vec![1, 3, 5]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the scale is zero along any axis, or if the bounds of this list can not be
+    /// mapped without overflow. If this list is not bounded, accessing an index that overflows
+    /// panics.
+    fn index_affine(
+        self,
+        scale: <Self::Index as Affine>::Scale,
+        offset: <Self::Index as Affine>::Offset,
+    ) -> IndexAffine<Self>
+    where
+        Self: Sized,
+        Self::Index: Affine,
+    {
+        IndexAffine::new(self, scale, offset)
+    }
+
+    /// Maps each index in this index list with the provided function.
+    ///
+    /// The result is not unique in general. Use [`IndexMap::assume_injective`] to promise that
+    /// the function is injective, in which case the result is unique whenever this list is.
+    /// The result has no bounds, unless provided through [`IndexMap::assume_bounds`].
+    ///
+    /// # Safety
+    ///
+    /// The function **must** be deterministic on the indices in this list: every call with the
+    /// same index must return the same result, for as long as the mapped list exists.
+    /// Functions that depend on mutable state, for example through interior mutability,
+    /// must not be used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use paradis::index::{collect_indices, IndexList};
+    ///
+    /// // SAFETY: The function is deterministic
+    /// let mapped = unsafe { (0 .. 3).index_map(|i| (i, 2 * i)) };
+    /// let mapped: Vec<_> = collect_indices(mapped);
+    /// assert_eq!(mapped, vec![(0, 0), (1, 2), (2, 4)]);
+    /// ```
+    unsafe fn index_map<F, J>(self, f: F) -> IndexMap<Self, F, J>
+    where
+        Self: Sized,
+        F: Fn(Self::Index) -> J,
+    {
+        unsafe { IndexMap::new(self, f) }
+    }
+
     /// Flattens nested tuple indices.
     ///
     /// TODO: More docs, examples
@@ -148,16 +471,98 @@ pub unsafe trait IndexList: Sync + Send {
     /// Turns an index list into a list of unique indices, if possible.
     ///
     /// Checks that all indices are unique, and also determines their bounds.
+    /// The strategy used for checking is selected automatically,
+    /// see [`CheckedUnique::from_indices`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the indices are not unique.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the extent of the bounds of the indices does not fit in the index type.
+    fn check_unique(self) -> Result<CheckedUnique<Self>, NonUniqueIndex<Self::Index>>
+    where
+        Self: Sized,
+        Self::Index: RecordIndex,
+    {
+        CheckedUnique::from_indices(self)
+    }
+
+    /// Turns an index list into a list of unique indices, if possible, checking in parallel.
+    ///
+    /// This is the parallel counterpart of [`check_unique`](Self::check_unique),
+    /// see [`CheckedUnique::par_from_indices`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use paradis::index::IndexList;
+    ///
+    /// let indices = vec![3usize, 0, 2].par_check_unique().unwrap();
+    /// assert_eq!(indices.bounds().unwrap().extent, 4);
+    ///
+    /// assert!(vec![3usize, 0, 3].par_check_unique().is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the indices are not unique.
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    #[cfg(feature = "rayon")]
+    fn par_check_unique(self) -> Result<CheckedUnique<Self>, NonUniqueIndex<Self::Index>>
+    where
+        Self: Sized,
+        Self::Index: RecordIndex,
+    {
+        CheckedUnique::par_from_indices(self)
+    }
+
+    /// Turns a sorted index list into a list of unique indices, if possible.
+    ///
+    /// Checks that the indices are strictly increasing in a single linear pass,
+    /// which is much cheaper than [`check_unique`](Self::check_unique).
+    /// The resulting [`SortedUnique`] also supports `O(log n)` lookups.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use paradis::index::IndexList;
+    ///
+    /// let indices = vec![1usize, 4, 7].check_unique_sorted().unwrap();
+    /// assert_eq!(indices.position(4), Some(1));
+    /// assert!(!indices.contains(5));
+    ///
+    /// assert!(vec![1usize, 7, 4].check_unique_sorted().is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the indices are not strictly increasing.
+    fn check_unique_sorted(self) -> Result<SortedUnique<Self>, NotStrictlyIncreasing>
+    where
+        Self: Sized,
+        Self::Index: RecordIndex,
+    {
+        SortedUnique::from_sorted_indices(self)
+    }
+
+    /// Sorts a copy of the indices in this list, and checks that they are unique.
+    ///
+    /// Note that the order of the indices in the result is generally different from the
+    /// order in this list.
     ///
     /// # Errors
     ///
     /// Returns an error if the indices are not unique.
-    fn check_unique(self) -> Result<CheckedUnique<Self>, NonUniqueIndex>
+    fn sort_and_check_unique(
+        self,
+    ) -> Result<SortedUnique<Vec<Self::Index>>, NonUniqueIndex<Self::Index>>
     where
         Self: Sized,
         Self::Index: RecordIndex,
     {
-        CheckedUnique::from_hashable_indices(self)
+        SortedUnique::from_unsorted_indices(self)
     }
 
     /// Turns an index list into a list of unique indices, without checking.
@@ -176,7 +581,7 @@ pub unsafe trait IndexList: Sync + Send {
     }
 }
 
-unsafe impl<'a, I: IndexList> IndexList for &'a I {
+unsafe impl<I: IndexList> IndexList for &I {
     type Index = I::Index;
 
     const ALWAYS_BOUNDED: bool = I::ALWAYS_BOUNDED;
@@ -192,6 +597,10 @@ unsafe impl<'a, I: IndexList> IndexList for &'a I {
     fn bounds(&self) -> Option<Bounds<Self::Index>> {
         I::bounds(self)
     }
+
+    fn contiguous_bounds(&self) -> Option<Bounds<Self::Index>> {
+        I::contiguous_bounds(self)
+    }
 }
 
 /// A finite list of *unique* indices.
@@ -201,4 +610,4 @@ unsafe impl<'a, I: IndexList> IndexList for &'a I {
 /// All indices in the list *must* be unique.
 pub unsafe trait UniqueIndexList: IndexList {}
 
-unsafe impl<'a, I: UniqueIndexList> UniqueIndexList for &'a I {}
+unsafe impl<I: UniqueIndexList> UniqueIndexList for &I {}
@@ -1,5 +1,6 @@
-use crate::index::{IndexList, UniqueIndexList};
+use crate::index::{IndexList, UniqueIndexList, Unravel};
 use paradis_core::Bounds;
+use std::iter::StepBy;
 use std::ops::{Range, RangeInclusive};
 
 unsafe impl IndexList for Range<usize> {
@@ -23,6 +24,11 @@ unsafe impl IndexList for Range<usize> {
             extent: self.num_indices(),
         })
     }
+
+    #[inline]
+    fn contiguous_bounds(&self) -> Option<Bounds<Self::Index>> {
+        self.bounds()
+    }
 }
 
 unsafe impl UniqueIndexList for Range<usize> {}
@@ -48,10 +54,151 @@ unsafe impl IndexList for RangeInclusive<usize> {
             extent: self.num_indices(),
         })
     }
+
+    #[inline]
+    fn contiguous_bounds(&self) -> Option<Bounds<Self::Index>> {
+        self.bounds()
+    }
 }
 
 unsafe impl UniqueIndexList for RangeInclusive<usize> {}
 
+/// The number of indices in a stepped range that ends at `usize::MAX`.
+///
+/// This is the only case in which the length of the underlying range does not fit in `usize`.
+/// The step of [`StepBy`] is not exposed, so it is recovered from the first two indices.
+fn stepped_range_to_max_len(mut range: impl Iterator<Item = usize>) -> usize {
+    let Some(first) = range.next() else {
+        return 0;
+    };
+    let Some(second) = range.next() else {
+        return 1;
+    };
+    let step = second - first;
+    ((usize::MAX - first) / step)
+        .checked_add(1)
+        .expect("number of indices must fit in usize")
+}
+
+/// Implements [`IndexList`] and [`UniqueIndexList`] for stepped ranges obtained through
+/// [`Iterator::step_by`].
+macro_rules! impl_step_by_index_list {
+    ($range:ty) => {
+        unsafe impl IndexList for StepBy<$range> {
+            type Index = usize;
+            const ALWAYS_BOUNDED: bool = true;
+
+            #[inline]
+            unsafe fn get_index_unchecked(&self, loc: usize) -> usize {
+                // nth is constant-time for stepped ranges
+                let index = self.clone().nth(loc);
+                // SAFETY: The caller guarantees that loc < num_indices
+                unsafe { index.unwrap_unchecked() }
+            }
+
+            #[inline]
+            fn num_indices(&self) -> usize {
+                // The size hint is exact, unless the length of the underlying range overflows
+                let (lower, upper) = self.size_hint();
+                if upper == Some(lower) {
+                    lower
+                } else {
+                    stepped_range_to_max_len(self.clone())
+                }
+            }
+
+            #[inline]
+            fn bounds(&self) -> Option<Bounds<Self::Index>> {
+                let n = self.num_indices();
+                if n == 0 {
+                    Some(Bounds::new_empty())
+                } else {
+                    let first = self.get_index(0);
+                    let last = self.get_index(n - 1);
+                    let extent = (last - first)
+                        .checked_add(1)
+                        .expect("extent of bounds must fit in usize");
+                    Some(Bounds {
+                        offset: first,
+                        extent,
+                    })
+                }
+            }
+
+            #[inline]
+            fn contiguous_bounds(&self) -> Option<Bounds<Self::Index>> {
+                // Only a step of one, or at most one index, leaves no gaps
+                self.bounds()
+                    .filter(|bounds| bounds.extent == self.num_indices())
+            }
+        }
+
+        // Steps are always non-zero, since `step_by(0)` panics
+        unsafe impl UniqueIndexList for StepBy<$range> {}
+    };
+}
+
+impl_step_by_index_list!(Range<usize>);
+impl_step_by_index_list!(RangeInclusive<usize>);
+
+macro_rules! replace_with_usize {
+    ($content:tt) => {
+        usize
+    };
+}
+
+macro_rules! replace_with_range {
+    ($content:tt) => {
+        Range<usize>
+    };
+}
+
+/// Implements [`IndexList`] and [`UniqueIndexList`] for tuples of ranges, which describe
+/// the indices of a multi-dimensional box in row-major order.
+macro_rules! impl_range_tuple_index_list {
+    ($($idx:tt),*) => {
+        unsafe impl IndexList for ($(replace_with_range!($idx)),*) {
+            type Index = ($(replace_with_usize!($idx)),*);
+            const ALWAYS_BOUNDED: bool = true;
+
+            #[inline]
+            unsafe fn get_index_unchecked(&self, loc: usize) -> Self::Index {
+                let bounds = Bounds {
+                    offset: ($(self.$idx.start),*),
+                    extent: ($(self.$idx.num_indices()),*),
+                };
+                unsafe { bounds.get_index_unchecked(loc) }
+            }
+
+            #[inline]
+            fn num_indices(&self) -> usize {
+                let extent = ($(self.$idx.num_indices()),*);
+                Unravel::checked_volume(extent).expect("number of indices must fit in usize")
+            }
+
+            #[inline]
+            fn bounds(&self) -> Option<Bounds<Self::Index>> {
+                Some(Bounds {
+                    offset: ($(self.$idx.start),*),
+                    extent: ($(self.$idx.num_indices()),*),
+                })
+            }
+
+            #[inline]
+            fn contiguous_bounds(&self) -> Option<Bounds<Self::Index>> {
+                self.bounds()
+            }
+        }
+
+        unsafe impl UniqueIndexList for ($(replace_with_range!($idx)),*) {}
+    };
+}
+
+impl_range_tuple_index_list!(0, 1);
+impl_range_tuple_index_list!(0, 1, 2);
+impl_range_tuple_index_list!(0, 1, 2, 3);
+impl_range_tuple_index_list!(0, 1, 2, 3, 4);
+
 unsafe impl<I: Copy + Send + Sync> IndexList for Vec<I> {
     type Index = I;
     const ALWAYS_BOUNDED: bool = false;
@@ -0,0 +1,110 @@
+use crate::index::{IndexList, UniqueIndexList};
+use paradis_core::Bounds;
+use std::ops::{Range, RangeInclusive};
+
+/// A range of `u32` or `u64` indices, as an index list.
+///
+/// Obtained from a [`Range`] or a [`RangeInclusive`] through [`From`]. Ranges that end before
+/// they start are empty.
+///
+/// Ranges of `usize` are index lists themselves. Ranges of other integer types must be
+/// converted explicitly, since implementing [`IndexList`] for them directly would make the
+/// type of integer literal ranges such as `0 .. 3` ambiguous. The indices can be converted
+/// to `usize` with [`index_cast`](IndexList::index_cast).
+///
+/// # Examples
+///
+/// ```
+/// use paradis::index::{collect_indices, IndexList, IndexRange};
+/// use paradis::Bounds;
+///
+/// let indices = IndexRange::from(2u32 .. 5);
+/// assert_eq!(indices.bounds(), Some(Bounds { offset: 2, extent: 3 }));
+/// let indices: Vec<usize> = collect_indices(indices.index_cast());
+/// assert_eq!(indices, vec![2, 3, 4]);
+///
+/// let indices = IndexRange::from(2u64 ..= 5);
+/// assert_eq!(indices.num_indices(), 4);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndexRange<I> {
+    start: I,
+    extent: I,
+}
+
+macro_rules! impl_index_range {
+    ($ty:ty) => {
+        impl IndexRange<$ty> {
+            fn new(start: $ty, end: $ty) -> Self {
+                let extent = end.saturating_sub(start);
+                assert!(
+                    usize::try_from(extent).is_ok(),
+                    "number of indices must fit in usize"
+                );
+                Self { start, extent }
+            }
+        }
+
+        impl From<Range<$ty>> for IndexRange<$ty> {
+            /// # Panics
+            ///
+            /// Panics if the number of indices does not fit in `usize`.
+            fn from(range: Range<$ty>) -> Self {
+                Self::new(range.start, range.end)
+            }
+        }
+
+        impl From<RangeInclusive<$ty>> for IndexRange<$ty> {
+            /// # Panics
+            ///
+            /// Panics if the end of the range is the largest value of the index type, or if
+            /// the number of indices does not fit in `usize`.
+            fn from(range: RangeInclusive<$ty>) -> Self {
+                let (start, end) = range.into_inner();
+                if end < start {
+                    Self::new(start, start)
+                } else {
+                    let end = end.checked_add(1).expect("range end must not overflow");
+                    Self::new(start, end)
+                }
+            }
+        }
+
+        unsafe impl IndexList for IndexRange<$ty> {
+            type Index = $ty;
+            const ALWAYS_BOUNDED: bool = true;
+
+            #[inline(always)]
+            unsafe fn get_index_unchecked(&self, loc: usize) -> $ty {
+                // The location is smaller than the extent, so it fits in the index type
+                self.start + loc as $ty
+            }
+
+            #[inline(always)]
+            fn num_indices(&self) -> usize {
+                self.extent as usize
+            }
+
+            #[inline]
+            fn bounds(&self) -> Option<Bounds<$ty>> {
+                Some(Bounds {
+                    offset: self.start,
+                    extent: self.extent,
+                })
+            }
+
+            #[inline]
+            fn contiguous_bounds(&self) -> Option<Bounds<$ty>> {
+                self.bounds()
+            }
+        }
+
+        unsafe impl UniqueIndexList for IndexRange<$ty> {}
+    };
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64",))]
+impl_index_range!(u32);
+
+#[cfg(target_pointer_width = "64")]
+impl_index_range!(u64);
@@ -1,20 +1,35 @@
 //! Construction of index lists, and facilities for access narrowing.
 use crate::error::OutOfBounds;
-use paradis_core::{IntoParAccess, RecordIndex};
+use combinators::{IndexLists, IndexProductN};
+use paradis_core::{IntoParAccess, RecordIndex, SharedParAccess};
 
+pub mod coloring;
 pub mod combinators;
+pub mod partition;
 pub mod patterns;
 
 mod assumed_unique;
 mod checked_unique;
+mod disjoint_classes;
+mod index_fn;
 mod index_list;
 mod index_list_impl_std;
+mod index_range;
 mod narrowed_access;
+mod order;
+mod permutation;
+mod sorted_unique;
 
 pub use assumed_unique::AssumedUnique;
 pub use checked_unique::CheckedUnique;
+pub use disjoint_classes::DisjointClasses;
+pub use index_fn::IndexFn;
 pub use index_list::{IndexList, UniqueIndexList};
-pub use narrowed_access::NarrowedAccess;
+pub use index_range::IndexRange;
+pub use narrowed_access::{NarrowedAccess, SharedNarrowedAccess};
+pub use order::{ColumnMajor, IndexOrder, RowMajor, Unravel};
+pub use permutation::Permutation;
+pub use sorted_unique::SortedUnique;
 
 /// Narrows an access object to a subset of its index set.
 ///
@@ -32,7 +47,7 @@ pub use narrowed_access::NarrowedAccess;
 pub fn narrow_access<IntoAccess, Indices>(
     access: IntoAccess,
     indices: &Indices,
-) -> Result<NarrowedAccess<'_, Indices, IntoAccess::Access>, OutOfBounds>
+) -> Result<NarrowedAccess<'_, Indices, IntoAccess::Access>, OutOfBounds<Indices::Index>>
 where
     Indices: UniqueIndexList,
     Indices::Index: RecordIndex,
@@ -41,6 +56,80 @@ where
     NarrowedAccess::try_new(indices, access.into_par_access())
 }
 
+/// Narrows an access object with shared records to a list of indices, which may be repeated.
+///
+/// Unlike [`narrow_access`], the indices need not be unique, since the records of a
+/// [`SharedParAccess`], such as [`SliceParAccess`](crate::slice::SliceParAccess), may be
+/// obtained any number of times. Index lists without bounds are checked index by index.
+///
+/// # Errors
+///
+/// Returns an [`OutOfBounds`] error if the indices are not contained in the bounds of the
+/// collection.
+///
+/// # Examples
+///
+/// ```
+/// use paradis::index::narrow_shared_access;
+/// use paradis::slice::SliceParAccess;
+/// use paradis::{BoundedParAccess, LinearParAccess};
+///
+/// let data = vec!['a', 'b', 'c'];
+/// let indices = vec![2, 0, 2];
+/// let access = narrow_shared_access(SliceParAccess::from_slice(&data), &indices).unwrap();
+/// assert_eq!(access.collection_len(), 3);
+/// assert_eq!(unsafe { access.get_unsync(0) }, &'c');
+/// assert_eq!(unsafe { access.get_unsync(2) }, &'c');
+///
+/// assert!(narrow_shared_access(SliceParAccess::from_slice(&data), &vec![1, 3]).is_err());
+/// ```
+pub fn narrow_shared_access<IntoAccess, Indices>(
+    access: IntoAccess,
+    indices: &Indices,
+) -> Result<SharedNarrowedAccess<'_, Indices, IntoAccess::Access>, OutOfBounds<Indices::Index>>
+where
+    Indices: IndexList,
+    Indices::Index: RecordIndex,
+    IntoAccess: IntoParAccess<Indices::Index>,
+    IntoAccess::Access: SharedParAccess<Indices::Index>,
+{
+    SharedNarrowedAccess::try_new(indices, access.into_par_access())
+}
+
+/// Returns the Cartesian product of a tuple of index lists, visited in the given order.
+///
+/// The indices of the product are flat tuples with one index from each list. With
+/// [`RowMajor`] order, the index from the *last* list varies fastest, as for
+/// [`index_product`](IndexList::index_product). With [`ColumnMajor`] order, the index
+/// from the *first* list varies fastest, which matches the memory layout of
+/// column-major collections such as `nalgebra` matrices.
+///
+/// The product is unique if every list is unique.
+///
+/// # Examples
+///
+/// ```
+/// use paradis::index::{collect_indices, index_product_n, ColumnMajor, RowMajor};
+///
+/// let indices: Vec<_> = collect_indices(index_product_n((0 .. 2, 0 .. 1, 5 .. 7), RowMajor));
+/// assert_eq!(indices, vec![(0, 0, 5), (0, 0, 6), (1, 0, 5), (1, 0, 6)]);
+///
+/// let indices: Vec<_> = collect_indices(index_product_n((0 .. 2, 5 .. 7), ColumnMajor));
+/// assert_eq!(indices, vec![(0, 5), (1, 5), (0, 6), (1, 6)]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the number of indices in the product does not fit in `usize`.
+/// Use [`IndexProductN::try_new`] to handle this case.
+pub fn index_product_n<Lists, Order>(lists: Lists, order: Order) -> IndexProductN<Lists, Order>
+where
+    Lists: IndexLists,
+    Order: IndexOrder,
+{
+    IndexProductN::new(lists, order)
+}
+
 /// Collects an index list into the desired collection.
 ///
 /// This is a convenience feature intended mainly for debugging and tests.
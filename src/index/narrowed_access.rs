@@ -1,7 +1,7 @@
 use crate::error::OutOfBounds;
 use crate::index::{IndexList, UniqueIndexList};
 use crate::{BoundedParAccess, Bounds, LinearParAccess, RecordIndex};
-use paradis_core::ParAccess;
+use paradis_core::{ParAccess, SharedParAccess};
 use std::any::type_name;
 
 /// An access object that has been narrowed to a subset of its indices.
@@ -25,16 +25,28 @@ where
     Indices::Index: RecordIndex,
     Access: BoundedParAccess<Indices::Index>,
 {
-    pub(crate) fn try_new(indices: &'a Indices, access: Access) -> Result<Self, OutOfBounds> {
+    pub(crate) fn try_new(
+        indices: &'a Indices,
+        access: Access,
+    ) -> Result<Self, OutOfBounds<Indices::Index>> {
         if let Some(index_bounds) = indices.bounds() {
-            if access.bounds().contains_bounds(&index_bounds) {
+            let access_bounds = access.bounds();
+            if access_bounds.contains_bounds(&index_bounds) {
                 Ok(Self {
                     indices,
                     access,
                     verified_in_bounds: true,
                 })
             } else {
-                Err(OutOfBounds)
+                // Bounds need not be tight, so there may not be an offending index
+                let offending_index = (0..indices.num_indices())
+                    .map(|loc| (loc, indices.get_index(loc)))
+                    .find(|&(_, index)| !access_bounds.contains_index(index));
+                Err(OutOfBounds {
+                    index_bounds,
+                    access_bounds,
+                    offending_index,
+                })
             }
         } else {
             assert!(
@@ -53,18 +65,9 @@ where
             })
         }
     }
-}
-
-unsafe impl<'a, Indices, Access> ParAccess<usize> for NarrowedAccess<'a, Indices, Access>
-where
-    Indices: UniqueIndexList,
-    Indices::Index: RecordIndex,
-    Access: BoundedParAccess<Indices::Index>,
-{
-    type Record = Access::Record;
 
     #[inline(always)]
-    unsafe fn clone_access(&self) -> Self {
+    unsafe fn clone_narrowed(&self) -> Self {
         Self {
             indices: self.indices,
             access: unsafe { self.access.clone_access() },
@@ -72,8 +75,12 @@ where
         }
     }
 
+    /// Obtain the record at the index at the given location.
+    ///
+    /// The location must be smaller than the number of indices, and the caller must uphold the
+    /// safety requirements of [`ParAccess::get_unsync_unchecked`] for the record at that index.
     #[inline(always)]
-    unsafe fn get_unsync_unchecked(&self, loc: usize) -> Self::Record {
+    unsafe fn get_narrowed_unchecked(&self, loc: usize) -> Access::Record {
         // SAFETY: Since this is an unchecked method, we can always directly try to obtain
         // the index at the requested location in the index list
         let index = unsafe { self.indices.get_index_unchecked(loc) };
@@ -96,6 +103,26 @@ where
     }
 }
 
+unsafe impl<'a, Indices, Access> ParAccess<usize> for NarrowedAccess<'a, Indices, Access>
+where
+    Indices: UniqueIndexList,
+    Indices::Index: RecordIndex,
+    Access: BoundedParAccess<Indices::Index>,
+{
+    type Record = Access::Record;
+
+    #[inline(always)]
+    unsafe fn clone_access(&self) -> Self {
+        unsafe { self.clone_narrowed() }
+    }
+
+    #[inline(always)]
+    unsafe fn get_unsync_unchecked(&self, loc: usize) -> Self::Record {
+        // SAFETY: The indices are unique, so distinct locations refer to distinct records
+        unsafe { self.get_narrowed_unchecked(loc) }
+    }
+}
+
 unsafe impl<'a, Indices, Access> BoundedParAccess<usize> for NarrowedAccess<'a, Indices, Access>
 where
     Indices: UniqueIndexList,
@@ -121,3 +148,109 @@ where
         self.indices.num_indices()
     }
 }
+
+/// An access object with shared records that has been narrowed to a list of indices,
+/// which may be repeated.
+///
+/// This is the result type for
+/// [`narrow_shared_access`](crate::index::narrow_shared_access).
+#[derive(Debug)]
+pub struct SharedNarrowedAccess<'a, Indices, Access>(NarrowedAccess<'a, Indices, Access>);
+
+impl<'a, Indices, Access> SharedNarrowedAccess<'a, Indices, Access>
+where
+    Indices: IndexList,
+    Indices::Index: RecordIndex,
+    Access: SharedParAccess<Indices::Index> + BoundedParAccess<Indices::Index>,
+{
+    pub(crate) fn try_new(
+        indices: &'a Indices,
+        access: Access,
+    ) -> Result<Self, OutOfBounds<Indices::Index>> {
+        if indices.bounds().is_some() {
+            return NarrowedAccess::try_new(indices, access).map(Self);
+        }
+
+        // Lists with repeated indices, such as a `Vec`, are rarely bounded, so check every
+        // index up front rather than on every access
+        let access_bounds = access.bounds();
+        let offending_index = (0..indices.num_indices())
+            .map(|loc| (loc, indices.get_index(loc)))
+            .find(|&(_, index)| !access_bounds.contains_index(index));
+        if offending_index.is_some() {
+            let mut index_bounds = Bounds::new_empty();
+            for loc in 0..indices.num_indices() {
+                index_bounds.enclose_index(indices.get_index(loc));
+            }
+            return Err(OutOfBounds {
+                index_bounds,
+                access_bounds,
+                offending_index,
+            });
+        }
+        Ok(Self(NarrowedAccess {
+            indices,
+            access,
+            verified_in_bounds: true,
+        }))
+    }
+}
+
+unsafe impl<'a, Indices, Access> ParAccess<usize> for SharedNarrowedAccess<'a, Indices, Access>
+where
+    Indices: IndexList,
+    Indices::Index: RecordIndex,
+    Access: SharedParAccess<Indices::Index> + BoundedParAccess<Indices::Index>,
+{
+    type Record = Access::Record;
+
+    #[inline(always)]
+    unsafe fn clone_access(&self) -> Self {
+        Self(unsafe { self.0.clone_narrowed() })
+    }
+
+    #[inline(always)]
+    unsafe fn get_unsync_unchecked(&self, loc: usize) -> Self::Record {
+        // SAFETY: The records of the access may be obtained any number of times, so repeated
+        // indices are allowed
+        unsafe { self.0.get_narrowed_unchecked(loc) }
+    }
+}
+
+unsafe impl<'a, Indices, Access> BoundedParAccess<usize>
+    for SharedNarrowedAccess<'a, Indices, Access>
+where
+    Indices: IndexList,
+    Indices::Index: RecordIndex,
+    Access: SharedParAccess<Indices::Index> + BoundedParAccess<Indices::Index>,
+{
+    fn bounds(&self) -> Bounds<usize> {
+        Bounds {
+            offset: 0,
+            extent: self.0.indices.num_indices(),
+        }
+    }
+}
+
+unsafe impl<'a, Indices, Access> LinearParAccess for SharedNarrowedAccess<'a, Indices, Access>
+where
+    Indices: IndexList,
+    Indices::Index: RecordIndex,
+    Access: SharedParAccess<Indices::Index> + BoundedParAccess<Indices::Index>,
+{
+    #[inline(always)]
+    fn collection_len(&self) -> usize {
+        self.0.indices.num_indices()
+    }
+}
+
+// Every location refers to a record of the underlying access, which may be obtained any number
+// of times
+unsafe impl<'a, Indices, Access> SharedParAccess<usize>
+    for SharedNarrowedAccess<'a, Indices, Access>
+where
+    Indices: IndexList,
+    Indices::Index: RecordIndex,
+    Access: SharedParAccess<Indices::Index> + BoundedParAccess<Indices::Index>,
+{
+}
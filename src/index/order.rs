@@ -0,0 +1,127 @@
+use crate::internal::Sealed;
+
+/// The order in which multi-dimensional indices are visited.
+///
+/// This trait is *sealed*. The available orders are [`RowMajor`] and [`ColumnMajor`].
+pub trait IndexOrder: Sealed + Copy + Default + Send + Sync {
+    /// Compute the multi-dimensional index at the given location in a box with the given extents.
+    ///
+    /// The location must be smaller than the volume of the box.
+    fn unravel<I: Unravel>(loc: usize, extents: I) -> I;
+}
+
+/// Row-major order, in which the *last* index varies fastest.
+///
+/// This is the order used by [`IndexProduct`](crate::index::combinators::IndexProduct).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct RowMajor;
+
+/// Column-major order, in which the *first* index varies fastest.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ColumnMajor;
+
+impl Sealed for RowMajor {}
+impl Sealed for ColumnMajor {}
+
+impl IndexOrder for RowMajor {
+    #[inline(always)]
+    fn unravel<I: Unravel>(loc: usize, extents: I) -> I {
+        I::unravel_row_major(loc, extents)
+    }
+}
+
+impl IndexOrder for ColumnMajor {
+    #[inline(always)]
+    fn unravel<I: Unravel>(loc: usize, extents: I) -> I {
+        I::unravel_column_major(loc, extents)
+    }
+}
+
+/// Multi-dimensional indices that can be computed from a linear location in a box.
+///
+/// This is part of the machinery that drives multi-dimensional index lists.
+/// The trait is *sealed*, and implemented for `usize` and tuples of `usize`.
+pub trait Unravel: Sealed + Copy {
+    /// The number of indices in a box with the given extents, or `None` if it overflows `usize`.
+    fn checked_volume(extents: Self) -> Option<usize>;
+
+    /// Compute the index at the given location in a zero-offset box, in row-major order.
+    fn unravel_row_major(loc: usize, extents: Self) -> Self;
+
+    /// Compute the index at the given location in a zero-offset box, in column-major order.
+    fn unravel_column_major(loc: usize, extents: Self) -> Self;
+
+    /// Add an offset to this index along every dimension.
+    fn add_offset(self, offset: Self) -> Self;
+}
+
+impl Unravel for usize {
+    #[inline(always)]
+    fn checked_volume(extents: Self) -> Option<usize> {
+        Some(extents)
+    }
+
+    #[inline(always)]
+    fn unravel_row_major(loc: usize, _: Self) -> Self {
+        loc
+    }
+
+    #[inline(always)]
+    fn unravel_column_major(loc: usize, _: Self) -> Self {
+        loc
+    }
+
+    #[inline(always)]
+    fn add_offset(self, offset: Self) -> Self {
+        self + offset
+    }
+}
+
+macro_rules! replace_with_usize {
+    ($content:tt) => {
+        usize
+    };
+}
+
+/// Implements [`Unravel`] for a `usize` tuple. Row-major order requires the tuple
+/// positions in reverse.
+macro_rules! impl_tuple_unravel {
+    (($($idx:tt),*), ($($rev_idx:tt),*)) => {
+        impl Unravel for ($(replace_with_usize!($idx)),*) {
+            #[inline]
+            fn checked_volume(extents: Self) -> Option<usize> {
+                Some(1)$(.and_then(|volume: usize| volume.checked_mul(extents.$idx)))*
+            }
+
+            #[inline]
+            fn unravel_row_major(mut loc: usize, extents: Self) -> Self {
+                let mut index = extents;
+                $(
+                    index.$rev_idx = loc % extents.$rev_idx;
+                    loc /= extents.$rev_idx;
+                )*
+                index
+            }
+
+            #[inline]
+            fn unravel_column_major(mut loc: usize, extents: Self) -> Self {
+                let mut index = extents;
+                $(
+                    index.$idx = loc % extents.$idx;
+                    loc /= extents.$idx;
+                )*
+                index
+            }
+
+            #[inline]
+            fn add_offset(self, offset: Self) -> Self {
+                ($(self.$idx + offset.$idx),*)
+            }
+        }
+    };
+}
+
+impl_tuple_unravel!((0, 1), (1, 0));
+impl_tuple_unravel!((0, 1, 2), (2, 1, 0));
+impl_tuple_unravel!((0, 1, 2, 3), (3, 2, 1, 0));
+impl_tuple_unravel!((0, 1, 2, 3, 4), (4, 3, 2, 1, 0));
@@ -0,0 +1,277 @@
+//! Partitioning of unique index lists into disjoint pieces.
+//!
+//! A [`Partition`] splits a [`UniqueIndexList`] into pieces that are themselves unique index
+//! lists, and that are pairwise disjoint since every location of the list belongs to exactly
+//! one piece. This makes it possible to hand out a separate access object for each piece to
+//! a worker thread, for example with [`std::thread::scope`].
+use crate::error::OutOfBounds;
+use crate::index::patterns::GridIndex;
+use crate::index::{IndexList, NarrowedAccess, UniqueIndexList};
+use paradis_core::{Bounds, IntoParAccess, ParAccess, RecordIndex};
+use std::ops::Range;
+
+/// A partition of a unique index list into disjoint pieces.
+///
+/// # Examples
+///
+/// ```
+/// use paradis::index::partition::Partition;
+/// use paradis::iter::create_iter;
+///
+/// let indices = (0 .. 10).step_by(3);
+/// let mut data = vec![0; 10];
+/// let partition = Partition::contiguous(&indices, 2);
+/// let accesses = partition.narrow_access(data.as_mut_slice()).unwrap();
+/// std::thread::scope(|s| {
+///     for (piece, access) in accesses.into_iter().enumerate() {
+///         s.spawn(move || create_iter(access).for_each(|x| *x = piece + 1));
+///     }
+/// });
+/// assert_eq!(data, vec![1, 0, 0, 1, 0, 0, 2, 0, 0, 2]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Partition<'a, Indices>
+where
+    Indices: IndexList,
+    Indices::Index: RecordIndex,
+{
+    pieces: Vec<PartitionPiece<'a, Indices>>,
+}
+
+/// A piece of a [`Partition`].
+///
+/// The piece visits its indices in the same relative order as the partitioned list.
+#[derive(Debug, Clone)]
+pub struct PartitionPiece<'a, Indices>
+where
+    Indices: IndexList,
+    Indices::Index: RecordIndex,
+{
+    indices: &'a Indices,
+    locations: PieceLocations,
+    bounds: Option<Bounds<Indices::Index>>,
+}
+
+/// The locations in the partitioned list that belong to a piece.
+#[derive(Debug, Clone)]
+enum PieceLocations {
+    Contiguous(Range<usize>),
+    Listed(Vec<usize>),
+}
+
+impl<'a, Indices> Partition<'a, Indices>
+where
+    Indices: UniqueIndexList,
+    Indices::Index: RecordIndex,
+{
+    /// Partition an index list into `k` contiguous pieces of (almost) equal length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is zero.
+    pub fn contiguous(indices: &'a Indices, k: usize) -> Self {
+        assert!(k > 0, "number of pieces must be positive");
+        let n = indices.num_indices() as u128;
+        let boundaries = (0..=k).map(|p| (n * p as u128 / k as u128) as usize);
+        Self::from_boundaries(indices, boundaries)
+    }
+
+    /// Partition an index list into `k` contiguous pieces of (almost) equal total weight.
+    ///
+    /// Each piece ends at the first location at which the cumulative weight of the list
+    /// reaches the piece's share of the total weight. Pieces may therefore be empty if some
+    /// indices are much heavier than others.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is zero.
+    pub fn balanced<F>(indices: &'a Indices, k: usize, weight: F) -> Self
+    where
+        F: Fn(Indices::Index) -> usize,
+    {
+        assert!(k > 0, "number of pieces must be positive");
+        let n = indices.num_indices();
+        let cumulative_weights: Vec<u128> = (0..n)
+            .scan(0, |total, loc| {
+                *total += weight(indices.get_index(loc)) as u128;
+                Some(*total)
+            })
+            .collect();
+        let total_weight = cumulative_weights.last().copied().unwrap_or(0);
+        let boundaries = (0..=k).map(|p| {
+            if p == k {
+                return n;
+            }
+            // The smallest end such that the weight of the locations 0 .. end reaches the share
+            let share = total_weight * p as u128 / k as u128;
+            cumulative_weights.partition_point(|&w| w < share) + usize::from(share > 0)
+        });
+        Self::from_boundaries(indices, boundaries)
+    }
+
+    fn from_boundaries(indices: &'a Indices, boundaries: impl Iterator<Item = usize>) -> Self {
+        let boundaries: Vec<usize> = boundaries.collect();
+        let pieces = boundaries
+            .windows(2)
+            .map(|window| PartitionPiece {
+                indices,
+                locations: PieceLocations::Contiguous(window[0]..window[1]),
+                bounds: indices.bounds(),
+            })
+            .collect();
+        Self { pieces }
+    }
+
+    /// Narrow an access object to each piece of the partition.
+    ///
+    /// Since the pieces are disjoint, the returned access objects can be used at the same time,
+    /// for example by moving each of them to a different thread.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`OutOfBounds`] error for the first piece whose bounds are not contained
+    /// in the bounds of the collection.
+    #[allow(clippy::type_complexity)]
+    pub fn narrow_access<IntoAccess>(
+        &self,
+        access: IntoAccess,
+    ) -> Result<
+        Vec<NarrowedAccess<'_, PartitionPiece<'a, Indices>, IntoAccess::Access>>,
+        OutOfBounds<Indices::Index>,
+    >
+    where
+        IntoAccess: IntoParAccess<Indices::Index>,
+    {
+        let access = access.into_par_access();
+        self.pieces
+            .iter()
+            // SAFETY: The pieces are pairwise disjoint, so the narrowed accesses never
+            // obtain the same record
+            .map(|piece| NarrowedAccess::try_new(piece, unsafe { access.clone_access() }))
+            .collect()
+    }
+
+    /// The number of pieces.
+    pub fn num_pieces(&self) -> usize {
+        self.pieces.len()
+    }
+
+    /// The pieces of the partition.
+    pub fn pieces(&self) -> &[PartitionPiece<'a, Indices>] {
+        &self.pieces
+    }
+
+    /// Recover the pieces of the partition.
+    pub fn into_pieces(self) -> Vec<PartitionPiece<'a, Indices>> {
+        self.pieces
+    }
+}
+
+impl<'a, Indices> Partition<'a, Indices>
+where
+    Indices: UniqueIndexList,
+    Indices::Index: GridIndex,
+{
+    /// Partition an index list into `k` pieces by recursive bisection of their bounds.
+    ///
+    /// The indices are split at the median coordinate along the longest axis of their bounds,
+    /// and the two halves are split further until there are `k` pieces of (almost) equal
+    /// length. The indices of each piece are therefore close together, and each piece has
+    /// tight bounds. This takes `O(n log k)` time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is zero.
+    pub fn bisection(indices: &'a Indices, k: usize) -> Self {
+        assert!(k > 0, "number of pieces must be positive");
+        let mut locations: Vec<usize> = (0..indices.num_indices()).collect();
+        let mut pieces = Vec::with_capacity(k);
+        bisect(indices, &mut locations, k, &mut pieces);
+        Self { pieces }
+    }
+}
+
+/// Recursively bisect the given locations into `k` pieces, appending them in order.
+fn bisect<'a, Indices>(
+    indices: &'a Indices,
+    locations: &mut [usize],
+    k: usize,
+    pieces: &mut Vec<PartitionPiece<'a, Indices>>,
+) where
+    Indices: UniqueIndexList,
+    Indices::Index: GridIndex,
+{
+    let coordinates = |loc: usize| indices.get_index(loc).into_grid_index();
+    let mut bounds = Bounds::new_empty();
+    for &loc in locations.iter() {
+        bounds.enclose_index(indices.get_index(loc));
+    }
+
+    if k == 1 {
+        // Keep the relative order of the list within each piece
+        let mut locations = locations.to_vec();
+        locations.sort_unstable();
+        pieces.push(PartitionPiece {
+            indices,
+            locations: PieceLocations::Listed(locations),
+            bounds: Some(bounds),
+        });
+        return;
+    }
+
+    let grid_bounds = Indices::Index::into_grid_bounds(bounds);
+    let axis = (0..3)
+        .max_by_key(|&axis| grid_bounds.extent[axis])
+        .expect("there are three axes");
+    let k_left = k / 2;
+    let mid = (locations.len() as u128 * k_left as u128 / k as u128) as usize;
+    if mid < locations.len() {
+        locations.select_nth_unstable_by_key(mid, |&loc| (coordinates(loc)[axis], loc));
+    }
+    let (left, right) = locations.split_at_mut(mid);
+    bisect(indices, left, k_left, pieces);
+    bisect(indices, right, k - k_left, pieces);
+}
+
+unsafe impl<'a, Indices> IndexList for PartitionPiece<'a, Indices>
+where
+    Indices: UniqueIndexList,
+    Indices::Index: RecordIndex,
+{
+    type Index = Indices::Index;
+
+    const ALWAYS_BOUNDED: bool = Indices::ALWAYS_BOUNDED;
+
+    #[inline]
+    unsafe fn get_index_unchecked(&self, loc: usize) -> Self::Index {
+        match &self.locations {
+            PieceLocations::Contiguous(range) => unsafe {
+                self.indices.get_index_unchecked(range.start + loc)
+            },
+            PieceLocations::Listed(locations) => unsafe {
+                self.indices
+                    .get_index_unchecked(*locations.get_unchecked(loc))
+            },
+        }
+    }
+
+    #[inline]
+    fn num_indices(&self) -> usize {
+        match &self.locations {
+            PieceLocations::Contiguous(range) => range.len(),
+            PieceLocations::Listed(locations) => locations.len(),
+        }
+    }
+
+    fn bounds(&self) -> Option<Bounds<Self::Index>> {
+        self.bounds
+    }
+}
+
+/// Every piece contains distinct locations of a unique index list.
+unsafe impl<'a, Indices> UniqueIndexList for PartitionPiece<'a, Indices>
+where
+    Indices: UniqueIndexList,
+    Indices::Index: RecordIndex,
+{
+}
@@ -0,0 +1,197 @@
+use crate::index::patterns::triangular::{checked_num_indices, inverse_triangular, triangular};
+use crate::index::{IndexList, UniqueIndexList};
+use paradis_core::Bounds;
+
+/// The indices in a band around the main diagonal of a matrix with the given shape,
+/// in row-major order.
+///
+/// The band consists of the indices `(i, j)` with `-lower <= j - i <= upper`, i.e. the main
+/// diagonal, `lower` subdiagonals and `upper` superdiagonals. The band is clipped at the edges
+/// of the matrix. Obtaining an index takes `O(1)` time.
+///
+/// # Examples
+///
+/// ```
+/// use paradis::index::collect_indices;
+/// use paradis::index::patterns::Band;
+///
+/// // Tridiagonal
+/// let indices: Vec<_> = collect_indices(Band::new((3, 3), 1, 1));
+/// assert_eq!(indices, vec![(0, 0), (0, 1), (1, 0), (1, 1), (1, 2), (2, 1), (2, 2)]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Band {
+    shape: (usize, usize),
+    lower: usize,
+    upper: usize,
+    segments: [Segment; 3],
+    num_indices: usize,
+}
+
+/// Consecutive rows of the band whose lengths change by the same amount from row to row.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct Segment {
+    first_row: usize,
+    num_rows: usize,
+    first_loc: usize,
+    first_len: usize,
+    slope: Slope,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum Slope {
+    Increasing,
+    #[default]
+    Constant,
+    Decreasing,
+}
+
+impl Segment {
+    fn num_indices(&self) -> u128 {
+        let (rows, len) = (self.num_rows as u128, self.first_len as u128);
+        match self.slope {
+            _ if rows == 0 => 0,
+            Slope::Increasing => rows * len + triangular(rows - 1),
+            Slope::Constant => rows * len,
+            Slope::Decreasing => rows * len - triangular(rows - 1),
+        }
+    }
+
+    /// Determine the row within this segment and the position within that row of a location
+    /// relative to the start of the segment.
+    #[inline]
+    fn locate(&self, loc: usize) -> (usize, usize) {
+        let (loc, len) = (loc as u128, self.first_len as u128);
+        let (row, row_start) = match self.slope {
+            Slope::Constant => (loc / len, loc / len * len),
+            Slope::Increasing => {
+                // Rows have lengths len, len + 1, ..., so row t starts at T(t + len - 1) - T(len - 1)
+                let shift = triangular(len - 1);
+                let row = inverse_triangular(loc + shift) - (len - 1);
+                (row, triangular(row + len - 1) - shift)
+            }
+            Slope::Decreasing => {
+                // Rows have lengths len, len - 1, ..., so row t starts at T(len) - T(len - t)
+                let remaining = triangular(len) - loc;
+                let k = inverse_triangular(remaining - 1) + 1;
+                (len - k, triangular(len) - triangular(k))
+            }
+        };
+        (row as usize, (loc - row_start) as usize)
+    }
+}
+
+impl Band {
+    /// The band with `lower` subdiagonals and `upper` superdiagonals of a matrix with the
+    /// given shape.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of indices does not fit in `usize`.
+    pub fn new(shape: (usize, usize), lower: usize, upper: usize) -> Self {
+        let (m, n) = shape;
+        // Rows i >= n + lower lie entirely to the right of the matrix
+        let num_rows = if n == 0 {
+            0
+        } else {
+            m.min(n.saturating_add(lower))
+        };
+        // The left edge of the band is clipped for rows i < lower, and the right edge
+        // is clipped for rows i >= n - upper - 1
+        let left_clip_end = lower.min(num_rows);
+        let right_clip_start = n.saturating_sub(upper.saturating_add(1)).min(num_rows);
+        let boundaries = [
+            0,
+            left_clip_end.min(right_clip_start),
+            left_clip_end.max(right_clip_start),
+            num_rows,
+        ];
+        let slopes = [Slope::Increasing, Slope::Constant, Slope::Decreasing];
+
+        let mut segments = [Segment::default(); 3];
+        let mut num_indices = 0;
+        for (s, segment) in segments.iter_mut().enumerate() {
+            let first_row = boundaries[s];
+            *segment = Segment {
+                first_row,
+                num_rows: boundaries[s + 1] - first_row,
+                first_loc: checked_num_indices(num_indices),
+                first_len: row_len(shape, lower, upper, first_row),
+                slope: slopes[s],
+            };
+            num_indices += segment.num_indices();
+        }
+
+        Self {
+            shape,
+            lower,
+            upper,
+            segments,
+            num_indices: checked_num_indices(num_indices),
+        }
+    }
+
+    /// The number of subdiagonals in the band.
+    pub fn lower(&self) -> usize {
+        self.lower
+    }
+
+    /// The number of superdiagonals in the band.
+    pub fn upper(&self) -> usize {
+        self.upper
+    }
+
+    /// The shape of the matrix.
+    pub fn shape(&self) -> (usize, usize) {
+        self.shape
+    }
+}
+
+/// The number of indices in row `i` of the band.
+fn row_len(shape: (usize, usize), lower: usize, upper: usize, i: usize) -> usize {
+    let start = i.saturating_sub(lower);
+    let end = shape.1.min(i.saturating_add(upper).saturating_add(1));
+    end.saturating_sub(start)
+}
+
+unsafe impl IndexList for Band {
+    type Index = (usize, usize);
+
+    const ALWAYS_BOUNDED: bool = true;
+
+    #[inline]
+    unsafe fn get_index_unchecked(&self, loc: usize) -> (usize, usize) {
+        let segment = self
+            .segments
+            .iter()
+            .rev()
+            .find(|segment| segment.num_rows > 0 && segment.first_loc <= loc)
+            .unwrap_or(&self.segments[0]);
+        let (row, col) = segment.locate(loc - segment.first_loc);
+        let i = segment.first_row + row;
+        (i, i.saturating_sub(self.lower) + col)
+    }
+
+    #[inline]
+    fn num_indices(&self) -> usize {
+        self.num_indices
+    }
+
+    fn bounds(&self) -> Option<Bounds<(usize, usize)>> {
+        if self.num_indices == 0 {
+            return Some(Bounds::new_empty());
+        }
+        // Row 0 starts in column 0, and the last row ends furthest to the right
+        let last_row = self.segments[2].first_row + self.segments[2].num_rows - 1;
+        let num_cols = self
+            .shape
+            .1
+            .min(last_row.saturating_add(self.upper).saturating_add(1));
+        Some(Bounds {
+            offset: (0, 0),
+            extent: (last_row + 1, num_cols),
+        })
+    }
+}
+
+unsafe impl UniqueIndexList for Band {}
@@ -0,0 +1,233 @@
+use crate::index::patterns::GridIndex;
+use crate::index::{IndexList, UniqueIndexList};
+use paradis_core::Bounds;
+
+/// The indices within a given distance of the boundary of 1-D, 2-D or 3-D bounds, in row-major
+/// order.
+///
+/// An index belongs to the boundary if it lies within the first or last `width` indices along
+/// any axis, as with a ghost layer of the given width. The complement is [`Interior`]: for the
+/// same bounds and width, [`Boundary`] and [`Interior`] are disjoint and together contain every
+/// index in the bounds exactly once. If the bounds are too small to have an interior, every
+/// index belongs to the boundary. Obtaining an index takes `O(1)` time.
+///
+/// # Examples
+///
+/// ```
+/// use paradis::index::collect_indices;
+/// use paradis::index::patterns::Boundary;
+/// use paradis::Bounds;
+///
+/// let bounds = Bounds { offset: (0, 0), extent: (3, 4) };
+/// let indices: Vec<_> = collect_indices(Boundary::new(bounds, 1));
+/// assert_eq!(indices, vec![
+///     (0, 0), (0, 1), (0, 2), (0, 3),
+///     (1, 0),                 (1, 3),
+///     (2, 0), (2, 1), (2, 2), (2, 3),
+/// ]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Boundary<I> {
+    bounds: Bounds<I>,
+    width: usize,
+    grid: Bounds<[usize; 3]>,
+    // The range of interior coordinates along each axis, relative to the offset
+    interior: [(usize, usize); 3],
+    // The number of indices in the trailing axes d + 1 .. 3 of the box,
+    // and the number of those that belong to the boundary
+    volumes: [usize; 3],
+    boundary_volumes: [usize; 3],
+    num_indices: usize,
+}
+
+/// The indices at least a given distance away from the boundary of 1-D, 2-D or 3-D bounds,
+/// in row-major order.
+///
+/// The interior is the box obtained by removing `width` indices from both ends of every axis,
+/// and is empty if the bounds are too small. It is the complement of [`Boundary`]: for the
+/// same bounds and width, the two lists are disjoint and together contain every index in the
+/// bounds exactly once. Obtaining an index takes `O(1)` time.
+///
+/// # Examples
+///
+/// ```
+/// use paradis::index::collect_indices;
+/// use paradis::index::patterns::Interior;
+/// use paradis::Bounds;
+///
+/// let bounds = Bounds { offset: (0, 0), extent: (3, 4) };
+/// let indices: Vec<_> = collect_indices(Interior::new(bounds, 1));
+/// assert_eq!(indices, vec![(1, 1), (1, 2)]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Interior<I> {
+    bounds: Bounds<I>,
+    width: usize,
+    interior: Bounds<I>,
+    num_indices: usize,
+}
+
+/// Compute the range of interior coordinates along each axis of a zero-offset box with the
+/// given extents. The axes prepended by [`GridIndex::into_grid_bounds`] have no boundary.
+fn interior_ranges<I: GridIndex>(extents: [usize; 3], width: usize) -> [(usize, usize); 3] {
+    let num_padded_axes = 3 - I::NUM_AXES;
+    let mut ranges = [(0, 0); 3];
+    for (d, (range, extent)) in ranges.iter_mut().zip(extents).enumerate() {
+        let width = if d < num_padded_axes { 0 } else { width };
+        let start = width.min(extent);
+        *range = (start, start.max(extent.saturating_sub(width)));
+    }
+    ranges
+}
+
+impl<I: GridIndex> Boundary<I> {
+    /// The indices in the given bounds that lie within `width` indices of its boundary.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of indices in the bounds does not fit in `usize`.
+    pub fn new(bounds: Bounds<I>, width: usize) -> Self {
+        I::checked_volume(bounds.extent).expect("number of indices in bounds must fit in usize");
+        let grid = I::into_grid_bounds(bounds);
+        let interior = interior_ranges::<I>(grid.extent, width);
+
+        let mut volumes = [0; 3];
+        let mut boundary_volumes = [0; 3];
+        let (mut volume, mut interior_volume) = (1, 1);
+        for d in (0..3).rev() {
+            volumes[d] = volume;
+            boundary_volumes[d] = volume - interior_volume;
+            let (start, end) = interior[d];
+            volume *= grid.extent[d];
+            interior_volume *= end - start;
+        }
+
+        Self {
+            bounds,
+            width,
+            grid,
+            interior,
+            volumes,
+            boundary_volumes,
+            num_indices: volume - interior_volume,
+        }
+    }
+
+    /// The width of the boundary.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+}
+
+impl<I: GridIndex> Interior<I> {
+    /// The indices in the given bounds that lie at least `width` indices away from its boundary.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of indices in the bounds does not fit in `usize`.
+    pub fn new(bounds: Bounds<I>, width: usize) -> Self {
+        I::checked_volume(bounds.extent).expect("number of indices in bounds must fit in usize");
+        let grid = I::into_grid_bounds(bounds);
+        let ranges = interior_ranges::<I>(grid.extent, width);
+        let interior = Bounds {
+            offset: I::from_grid_index([0, 1, 2].map(|d| grid.offset[d] + ranges[d].0)),
+            extent: I::from_grid_index(ranges.map(|(start, end)| end - start)),
+        };
+        let num_indices = I::checked_volume(interior.extent)
+            .expect("interior is contained in the bounds, so its volume must fit in usize");
+
+        Self {
+            bounds,
+            width,
+            interior,
+            num_indices,
+        }
+    }
+
+    /// The width of the boundary that is excluded from the interior.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+}
+
+unsafe impl<I: GridIndex> IndexList for Boundary<I> {
+    type Index = I;
+
+    const ALWAYS_BOUNDED: bool = true;
+
+    #[inline]
+    unsafe fn get_index_unchecked(&self, mut loc: usize) -> I {
+        let mut index = self.grid.offset;
+        // Along each axis, the slabs before and after the interior range lie entirely on the
+        // boundary, whereas only the boundary of the remaining axes is visited in the slabs
+        // in between
+        for d in 0..3 {
+            let (start, end) = self.interior[d];
+            let (volume, boundary_volume) = (self.volumes[d], self.boundary_volumes[d]);
+            let middle_start = start * volume;
+            let middle_end = middle_start + (end - start) * boundary_volume;
+            if (middle_start..middle_end).contains(&loc) {
+                loc -= middle_start;
+                index[d] += start + loc / boundary_volume;
+                loc %= boundary_volume;
+                continue;
+            }
+
+            let (x, mut rest) = if loc < middle_start {
+                (loc / volume, loc % volume)
+            } else {
+                let loc = loc - middle_end;
+                (end + loc / volume, loc % volume)
+            };
+            index[d] += x;
+            // The trailing axes of a slab on the boundary are visited in full
+            for j in (d + 1..3).rev() {
+                let extent = self.grid.extent[j];
+                index[j] += rest % extent;
+                rest /= extent;
+            }
+            break;
+        }
+        I::from_grid_index(index)
+    }
+
+    #[inline]
+    fn num_indices(&self) -> usize {
+        self.num_indices
+    }
+
+    fn bounds(&self) -> Option<Bounds<I>> {
+        if self.num_indices == 0 {
+            return Some(Bounds::new_empty());
+        }
+        Some(self.bounds)
+    }
+}
+
+// The indices are visited in row-major order, skipping those in the interior
+unsafe impl<I: GridIndex> UniqueIndexList for Boundary<I> {}
+
+unsafe impl<I: GridIndex> IndexList for Interior<I> {
+    type Index = I;
+
+    const ALWAYS_BOUNDED: bool = true;
+
+    #[inline]
+    unsafe fn get_index_unchecked(&self, loc: usize) -> I {
+        I::unravel_row_major(loc, self.interior.extent).add_offset(self.interior.offset)
+    }
+
+    #[inline]
+    fn num_indices(&self) -> usize {
+        self.num_indices
+    }
+
+    fn bounds(&self) -> Option<Bounds<I>> {
+        if self.num_indices == 0 {
+            return Some(Bounds::new_empty());
+        }
+        Some(self.interior)
+    }
+}
+
+unsafe impl<I: GridIndex> UniqueIndexList for Interior<I> {}
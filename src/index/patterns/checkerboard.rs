@@ -0,0 +1,210 @@
+use crate::index::patterns::GridIndex;
+use crate::index::{DisjointClasses, IndexList, UniqueIndexList};
+use paradis_core::Bounds;
+
+/// The indices of a single colour in a `k`-colour checkerboard over 1-D, 2-D or 3-D bounds,
+/// in row-major order.
+///
+/// The colour of an index is the sum of its coordinates modulo the number of colours. With two
+/// colours, this is the red-black ordering used by Gauss-Seidel and SOR solvers: no two indices
+/// of the same colour are adjacent along an axis. Since colours are computed from absolute
+/// coordinates, checkerboards over neighbouring bounds fit together seamlessly.
+///
+/// Use [`colors`](Self::colors) to obtain every colour class at once. Constructing a
+/// checkerboard takes `O(k^2)` time, and obtaining an index takes `O(k)` time.
+///
+/// # Examples
+///
+/// ```
+/// use paradis::index::collect_indices;
+/// use paradis::index::patterns::Checkerboard;
+/// use paradis::Bounds;
+///
+/// let bounds = Bounds { offset: (0, 0), extent: (3, 3) };
+/// let red: Vec<_> = collect_indices(Checkerboard::new(bounds, 2, 0));
+/// assert_eq!(red, vec![(0, 0), (0, 2), (1, 1), (2, 0), (2, 2)]);
+///
+/// let black: Vec<_> = collect_indices(Checkerboard::new(bounds, 2, 1));
+/// assert_eq!(black, vec![(0, 1), (1, 0), (1, 2), (2, 1)]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Checkerboard<I> {
+    bounds: Bounds<I>,
+    grid: Bounds<[usize; 3]>,
+    num_colors: usize,
+    color: usize,
+    num_indices: usize,
+    // counts[d * k + r] is the number of cells in the trailing axes d + 1 .. 3 whose
+    // coordinate sum is congruent to r, and periods[d] is the sum over all r
+    counts: Vec<usize>,
+    periods: [usize; 3],
+}
+
+impl<I: GridIndex> Checkerboard<I> {
+    /// The indices in the given bounds that have the given colour, out of `num_colors` colours.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_colors` is zero, if `color >= num_colors`, or if the number of indices
+    /// in the bounds does not fit in `usize`.
+    pub fn new(bounds: Bounds<I>, num_colors: usize, color: usize) -> Self {
+        assert!(num_colors > 0, "number of colors must be positive");
+        assert!(
+            color < num_colors,
+            "color must be smaller than the number of colors"
+        );
+        I::checked_volume(bounds.extent).expect("number of indices in bounds must fit in usize");
+
+        let k = num_colors;
+        let grid = I::into_grid_bounds(bounds);
+        let mut counts = vec![0; 3 * k];
+        let mut periods = [0; 3];
+        // Count the cells of each colour in the trailing axes, starting from no axes at all
+        let mut suffix_counts = vec![0; k];
+        suffix_counts[0] = 1;
+        for d in (0..3).rev() {
+            counts[d * k..(d + 1) * k].copy_from_slice(&suffix_counts);
+            periods[d] = suffix_counts.iter().sum();
+
+            let mut next = vec![0; k];
+            for r in 0..k {
+                let num_congruent = count_congruent(grid.offset[d], grid.extent[d], r, k);
+                if num_congruent > 0 {
+                    for (s, &count) in suffix_counts.iter().enumerate() {
+                        next[add_mod(r, s, k)] += num_congruent * count;
+                    }
+                }
+            }
+            suffix_counts = next;
+        }
+
+        Self {
+            bounds,
+            grid,
+            num_colors,
+            color,
+            num_indices: suffix_counts[color],
+            counts,
+            periods,
+        }
+    }
+
+    /// Every colour class of a `k`-colour checkerboard over the given bounds, ordered by colour.
+    ///
+    /// The colour classes are pairwise disjoint, since every index has exactly one colour,
+    /// and together they contain every index in the bounds. A solver can therefore sweep the
+    /// colours one after another, and process the indices of each colour in parallel.
+    /// The returned [`DisjointClasses`] carries this guarantee, so it is not checked again.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_colors` is zero, or if the number of indices in the bounds does not
+    /// fit in `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use paradis::index::patterns::Checkerboard;
+    /// use paradis::index::IndexList;
+    /// use paradis::Bounds;
+    ///
+    /// let bounds = Bounds { offset: (0, 0, 0), extent: (4, 5, 6) };
+    /// let colors = Checkerboard::colors(bounds, 3);
+    /// assert_eq!(colors.len(), 3);
+    /// assert_eq!(colors.iter().map(|c| c.num_indices()).sum::<usize>(), 4 * 5 * 6);
+    /// ```
+    pub fn colors(bounds: Bounds<I>, num_colors: usize) -> DisjointClasses<Self> {
+        assert!(num_colors > 0, "number of colors must be positive");
+        let colors = (0..num_colors)
+            .map(|color| Self::new(bounds, num_colors, color))
+            .collect();
+        // SAFETY: Every index in the bounds has exactly one colour
+        unsafe { DisjointClasses::from_classes_unchecked(colors) }
+    }
+
+    /// The number of colours in the checkerboard.
+    pub fn num_colors(&self) -> usize {
+        self.num_colors
+    }
+
+    /// The colour of the indices in this list.
+    pub fn color(&self) -> usize {
+        self.color
+    }
+}
+
+/// The number of integers `x` with `offset <= x < offset + extent` and `x % k == r`.
+fn count_congruent(offset: usize, extent: usize, r: usize, k: usize) -> usize {
+    // Every run of k consecutive integers contains one such x, and the remaining
+    // extent % k integers start at a value congruent to offset
+    let in_remainder = sub_mod(r, offset % k, k) < extent % k;
+    extent / k + usize::from(in_remainder)
+}
+
+/// Compute `(a + b) % k` for `a, b < k` without overflow.
+#[inline(always)]
+fn add_mod(a: usize, b: usize, k: usize) -> usize {
+    if a >= k - b {
+        a - (k - b)
+    } else {
+        a + b
+    }
+}
+
+/// Compute `(a - b) mod k` for `a, b < k` without overflow.
+#[inline(always)]
+fn sub_mod(a: usize, b: usize, k: usize) -> usize {
+    if a >= b {
+        a - b
+    } else {
+        a + (k - b)
+    }
+}
+
+unsafe impl<I: GridIndex> IndexList for Checkerboard<I> {
+    type Index = I;
+
+    const ALWAYS_BOUNDED: bool = true;
+
+    #[inline]
+    unsafe fn get_index_unchecked(&self, mut loc: usize) -> I {
+        let k = self.num_colors;
+        let mut residue = self.color;
+        let mut index = [0; 3];
+        for (d, coordinate) in index.iter_mut().enumerate() {
+            let counts = &self.counts[d * k..(d + 1) * k];
+            // Any k consecutive coordinates along this axis together contain `period` indices
+            // of the remaining residue, so skip over whole periods before scanning
+            let period = self.periods[d];
+            let num_periods = loc / period;
+            loc -= num_periods * period;
+            let mut x = self.grid.offset[d] + num_periods * k;
+            loop {
+                let count = counts[sub_mod(residue, x % k, k)];
+                if loc < count {
+                    break;
+                }
+                loc -= count;
+                x += 1;
+            }
+            residue = sub_mod(residue, x % k, k);
+            *coordinate = x;
+        }
+        I::from_grid_index(index)
+    }
+
+    #[inline]
+    fn num_indices(&self) -> usize {
+        self.num_indices
+    }
+
+    fn bounds(&self) -> Option<Bounds<I>> {
+        if self.num_indices == 0 {
+            return Some(Bounds::new_empty());
+        }
+        Some(self.bounds)
+    }
+}
+
+// The indices are visited in row-major order, skipping those of other colours
+unsafe impl<I: GridIndex> UniqueIndexList for Checkerboard<I> {}
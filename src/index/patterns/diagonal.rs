@@ -0,0 +1,85 @@
+use crate::index::{IndexList, UniqueIndexList};
+use paradis_core::Bounds;
+
+/// The indices on the `k`-th diagonal of a matrix with the given shape.
+///
+/// The main diagonal is `k = 0`, superdiagonals have `k > 0` and subdiagonals have `k < 0`.
+/// That is, the indices are `(i, j)` with `j - i = k`, ordered by increasing `i`.
+/// The diagonal is clipped at the edges of the matrix, and may be empty.
+///
+/// # Examples
+///
+/// ```
+/// use paradis::index::collect_indices;
+/// use paradis::index::patterns::Diagonal;
+///
+/// let superdiagonal: Vec<_> = collect_indices(Diagonal::new((3, 5), 1));
+/// assert_eq!(superdiagonal, vec![(0, 1), (1, 2), (2, 3)]);
+///
+/// let subdiagonal: Vec<_> = collect_indices(Diagonal::new((3, 5), -2));
+/// assert_eq!(subdiagonal, vec![(2, 0)]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Diagonal {
+    k: isize,
+    shape: (usize, usize),
+    first: (usize, usize),
+    len: usize,
+}
+
+impl Diagonal {
+    /// The `k`-th diagonal of a matrix with the given shape.
+    pub fn new(shape: (usize, usize), k: isize) -> Self {
+        let (m, n) = shape;
+        let first = if k >= 0 {
+            (0, k.unsigned_abs())
+        } else {
+            (k.unsigned_abs(), 0)
+        };
+        let len = m.saturating_sub(first.0).min(n.saturating_sub(first.1));
+        Self {
+            k,
+            shape,
+            first,
+            len,
+        }
+    }
+
+    /// The diagonal, with `k = 0` denoting the main diagonal.
+    pub fn k(&self) -> isize {
+        self.k
+    }
+
+    /// The shape of the matrix.
+    pub fn shape(&self) -> (usize, usize) {
+        self.shape
+    }
+}
+
+unsafe impl IndexList for Diagonal {
+    type Index = (usize, usize);
+
+    const ALWAYS_BOUNDED: bool = true;
+
+    #[inline(always)]
+    unsafe fn get_index_unchecked(&self, loc: usize) -> (usize, usize) {
+        (self.first.0 + loc, self.first.1 + loc)
+    }
+
+    #[inline(always)]
+    fn num_indices(&self) -> usize {
+        self.len
+    }
+
+    fn bounds(&self) -> Option<Bounds<(usize, usize)>> {
+        if self.len == 0 {
+            return Some(Bounds::new_empty());
+        }
+        Some(Bounds {
+            offset: self.first,
+            extent: (self.len, self.len),
+        })
+    }
+}
+
+unsafe impl UniqueIndexList for Diagonal {}
@@ -0,0 +1,72 @@
+use crate::index::Unravel;
+use paradis_core::{Bounds, RecordIndex};
+
+/// Indices of 1-D, 2-D and 3-D structured grids, as used by patterns such as
+/// [`Morton`](super::Morton) and [`Checkerboard`](super::Checkerboard).
+///
+/// This trait is *sealed*, and implemented for `usize`, `(usize, usize)` and
+/// `(usize, usize, usize)`.
+pub trait GridIndex: RecordIndex + Unravel {
+    /// The number of axes of the index.
+    const NUM_AXES: usize;
+
+    /// Convert this index into an index with three axes, by prepending zero coordinates.
+    fn into_grid_index(self) -> [usize; 3];
+
+    /// Convert an index with three axes into this index type, by dropping prepended axes.
+    fn from_grid_index(index: [usize; 3]) -> Self;
+
+    /// Convert bounds into bounds with three axes, by prepending axes with offset zero
+    /// and extent one.
+    #[inline]
+    fn into_grid_bounds(bounds: Bounds<Self>) -> Bounds<[usize; 3]> {
+        let mut extent = bounds.extent.into_grid_index();
+        extent[..3 - Self::NUM_AXES].fill(1);
+        Bounds {
+            offset: bounds.offset.into_grid_index(),
+            extent,
+        }
+    }
+}
+
+impl GridIndex for usize {
+    const NUM_AXES: usize = 1;
+
+    #[inline]
+    fn into_grid_index(self) -> [usize; 3] {
+        [0, 0, self]
+    }
+
+    #[inline]
+    fn from_grid_index(index: [usize; 3]) -> Self {
+        index[2]
+    }
+}
+
+impl GridIndex for (usize, usize) {
+    const NUM_AXES: usize = 2;
+
+    #[inline]
+    fn into_grid_index(self) -> [usize; 3] {
+        [0, self.0, self.1]
+    }
+
+    #[inline]
+    fn from_grid_index(index: [usize; 3]) -> Self {
+        (index[1], index[2])
+    }
+}
+
+impl GridIndex for (usize, usize, usize) {
+    const NUM_AXES: usize = 3;
+
+    #[inline]
+    fn into_grid_index(self) -> [usize; 3] {
+        [self.0, self.1, self.2]
+    }
+
+    #[inline]
+    fn from_grid_index(index: [usize; 3]) -> Self {
+        (index[0], index[1], index[2])
+    }
+}
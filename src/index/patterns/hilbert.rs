@@ -0,0 +1,122 @@
+use crate::index::patterns::space_filling::{
+    impl_space_filling_pattern, new_pattern_parts, replace_with_usize, unrank, Curve,
+};
+use crate::index::{IndexList, UniqueIndexList};
+use paradis_core::Bounds;
+
+/// The indices in 2-D bounds, visited along a Hilbert curve.
+///
+/// On a square whose side length is a power of two, consecutive indices along the curve
+/// are always adjacent. For bounds whose extents are not powers of two, the order is that of
+/// the smallest enclosing square, skipping indices outside the bounds. Obtaining an index
+/// takes `O(log n)` time.
+///
+/// # Examples
+///
+/// ```
+/// use paradis::index::collect_indices;
+/// use paradis::index::patterns::Hilbert2d;
+/// use paradis::Bounds;
+///
+/// let hilbert = Hilbert2d::new(Bounds { offset: (0, 0), extent: (2, 2) });
+/// let indices: Vec<_> = collect_indices(hilbert);
+/// assert_eq!(indices, vec![(0, 0), (0, 1), (1, 1), (1, 0)]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hilbert2d {
+    bounds: Bounds<(usize, usize)>,
+    num_indices: usize,
+    levels: u32,
+}
+
+/// The indices in 3-D bounds, visited along a Hilbert curve.
+///
+/// See [`Hilbert2d`] for details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hilbert3d {
+    bounds: Bounds<(usize, usize, usize)>,
+    num_indices: usize,
+    levels: u32,
+}
+
+impl Hilbert2d {
+    /// Construct the Hilbert curve for the given bounds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of indices in the bounds does not fit in `usize`.
+    pub fn new(bounds: Bounds<(usize, usize)>) -> Self {
+        let (num_indices, levels) = new_pattern_parts(&bounds);
+        Self {
+            bounds,
+            num_indices,
+            levels,
+        }
+    }
+}
+
+impl Hilbert3d {
+    /// Construct the Hilbert curve for the given bounds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of indices in the bounds does not fit in `usize`.
+    pub fn new(bounds: Bounds<(usize, usize, usize)>) -> Self {
+        let (num_indices, levels) = new_pattern_parts(&bounds);
+        Self {
+            bounds,
+            num_indices,
+            levels,
+        }
+    }
+}
+
+/// The Hilbert curve in any dimension, following C. Hamilton, "Compact Hilbert Indices" (2006).
+///
+/// The orientation of the curve inside a cube is described by its entry corner and by the
+/// direction, i.e. the axis along which it leaves the first child cube.
+struct HilbertCurve;
+
+/// Rotate the lowest `D` bits of `x` to the left.
+#[inline(always)]
+fn rotate_left<const D: usize>(x: usize, r: usize) -> usize {
+    let r = r % D;
+    ((x << r) | (x >> (D - r))) & ((1 << D) - 1)
+}
+
+/// The binary reflected Gray code.
+#[inline(always)]
+fn gray(w: usize) -> usize {
+    w ^ (w >> 1)
+}
+
+impl<const D: usize> Curve<D> for HilbertCurve {
+    type State = (usize, usize);
+
+    #[inline(always)]
+    fn initial_state() -> (usize, usize) {
+        (0, 0)
+    }
+
+    #[inline(always)]
+    fn child((entry, direction): (usize, usize), w: usize) -> (usize, (usize, usize)) {
+        let position = rotate_left::<D>(gray(w), direction + 1) ^ entry;
+
+        // Entry corner and direction of the curve inside the w-th child, relative to the parent
+        let (child_entry, child_direction) = if w == 0 {
+            (0, 0)
+        } else if w % 2 == 1 {
+            (gray(w - 1), w.trailing_ones() as usize % D)
+        } else {
+            (gray(w - 2), (w - 1).trailing_ones() as usize % D)
+        };
+        let state = (
+            entry ^ rotate_left::<D>(child_entry, direction + 1),
+            (direction + child_direction + 1) % D,
+        );
+        (position, state)
+    }
+}
+
+impl_space_filling_pattern!(Hilbert2d, HilbertCurve, 2, (0, 1));
+impl_space_filling_pattern!(Hilbert3d, HilbertCurve, 3, (0, 1, 2));
@@ -1,4 +1,23 @@
 //! Common access patterns.
+mod band;
+mod boundary;
+mod checkerboard;
+mod diagonal;
+mod grid;
+mod hilbert;
+mod morton;
+mod multi_range;
 mod repeat;
+mod space_filling;
+mod triangular;
 
+pub use band::Band;
+pub use boundary::{Boundary, Interior};
+pub use checkerboard::Checkerboard;
+pub use diagonal::Diagonal;
+pub use grid::GridIndex;
+pub use hilbert::{Hilbert2d, Hilbert3d};
+pub use morton::Morton;
+pub use multi_range::MultiRange;
 pub use repeat::Repeat;
+pub use triangular::{LowerTriangular, UpperTriangular};
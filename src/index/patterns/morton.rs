@@ -0,0 +1,96 @@
+use crate::index::patterns::space_filling::{
+    impl_space_filling_pattern, new_pattern_parts, replace_with_usize, unrank, Curve,
+};
+use crate::index::patterns::GridIndex;
+use crate::index::{IndexList, UniqueIndexList};
+use paradis_core::Bounds;
+
+/// The indices in 1-D, 2-D or 3-D bounds, visited in Morton order (Z-order).
+///
+/// Morton order visits a cube whose side length is a power of two by recursively visiting
+/// its child cubes, with the first axis varying slowest. In 2-D, each `2 x 2` block is visited
+/// in the order `(0, 0)`, `(0, 1)`, `(1, 0)`, `(1, 1)`, whereas in 1-D it is the natural order.
+/// For bounds whose extents are not powers of two, the order is that of the smallest enclosing
+/// cube, skipping indices outside the bounds. Obtaining an index takes `O(log n)` time.
+///
+/// # Examples
+///
+/// ```
+/// use paradis::index::collect_indices;
+/// use paradis::index::patterns::Morton;
+/// use paradis::Bounds;
+///
+/// let morton = Morton::new(Bounds { offset: (0, 0), extent: (3, 3) });
+/// let indices: Vec<_> = collect_indices(morton);
+/// assert_eq!(indices, vec![
+///     (0, 0), (0, 1), (1, 0), (1, 1),
+///     (0, 2), (1, 2),
+///     (2, 0), (2, 1),
+///     (2, 2),
+/// ]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Morton<I> {
+    bounds: Bounds<I>,
+    num_indices: usize,
+    levels: u32,
+}
+
+/// Morton order on a cube, in which the first axis corresponds to the most significant bit.
+struct ZOrder;
+
+impl<I: GridIndex> Morton<I> {
+    /// Construct the Morton order for the given bounds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of indices in the bounds does not fit in `usize`.
+    pub fn new(bounds: Bounds<I>) -> Self {
+        let (num_indices, levels) = new_pattern_parts(&bounds);
+        Self {
+            bounds,
+            num_indices,
+            levels,
+        }
+    }
+}
+
+impl<const D: usize> Curve<D> for ZOrder {
+    type State = ();
+
+    #[inline(always)]
+    fn initial_state() {}
+
+    #[inline(always)]
+    fn child(_: (), w: usize) -> (usize, ()) {
+        // Reverse the bits of w, so that the first axis varies slowest
+        let position = (0..D).fold(0, |position, j| position | ((w >> (D - 1 - j)) & 1) << j);
+        (position, ())
+    }
+}
+
+unsafe impl IndexList for Morton<usize> {
+    type Index = usize;
+
+    const ALWAYS_BOUNDED: bool = true;
+
+    #[inline(always)]
+    unsafe fn get_index_unchecked(&self, loc: usize) -> usize {
+        self.bounds.offset + loc
+    }
+
+    #[inline]
+    fn num_indices(&self) -> usize {
+        self.num_indices
+    }
+
+    #[inline]
+    fn bounds(&self) -> Option<Bounds<usize>> {
+        Some(self.bounds)
+    }
+}
+
+unsafe impl UniqueIndexList for Morton<usize> {}
+
+impl_space_filling_pattern!(Morton<(usize, usize)>, ZOrder, 2, (0, 1));
+impl_space_filling_pattern!(Morton<(usize, usize, usize)>, ZOrder, 3, (0, 1, 2));
@@ -0,0 +1,108 @@
+use crate::index::{IndexList, IndexOrder, RowMajor, UniqueIndexList, Unravel};
+use paradis_core::Bounds;
+
+/// All indices contained in a (multi-dimensional) box, visited in the given order.
+///
+/// [`Bounds`] and tuples of ranges, such as `(0 .. m, 0 .. n)`, are themselves index lists
+/// that visit their indices in row-major order. Use [`MultiRange`] to choose the order
+/// explicitly.
+///
+/// # Examples
+///
+/// ```
+/// use paradis::index::{collect_indices, ColumnMajor};
+/// use paradis::index::patterns::MultiRange;
+/// use paradis::Bounds;
+///
+/// let bounds = Bounds { offset: (1, 0), extent: (2, 2) };
+/// let indices: Vec<_> = collect_indices(MultiRange::new(bounds, ColumnMajor));
+/// assert_eq!(indices, vec![(1, 0), (2, 0), (1, 1), (2, 1)]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MultiRange<I, Order = RowMajor> {
+    bounds: Bounds<I>,
+    num_indices: usize,
+    order: Order,
+}
+
+impl<I: Unravel, Order: IndexOrder> MultiRange<I, Order> {
+    /// Construct a multi-dimensional range for the given bounds, visited in the given order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of indices in the bounds does not fit in `usize`.
+    pub fn new(bounds: Bounds<I>, order: Order) -> Self {
+        let num_indices = I::checked_volume(bounds.extent)
+            .expect("number of indices in bounds must fit in usize");
+        Self {
+            bounds,
+            num_indices,
+            order,
+        }
+    }
+
+    /// The order in which indices are visited.
+    pub fn order(&self) -> Order {
+        self.order
+    }
+}
+
+unsafe impl<I, Order> IndexList for MultiRange<I, Order>
+where
+    I: Unravel + Send + Sync,
+    Order: IndexOrder,
+{
+    type Index = I;
+
+    const ALWAYS_BOUNDED: bool = true;
+
+    #[inline]
+    unsafe fn get_index_unchecked(&self, loc: usize) -> I {
+        Order::unravel(loc, self.bounds.extent).add_offset(self.bounds.offset)
+    }
+
+    #[inline]
+    fn num_indices(&self) -> usize {
+        self.num_indices
+    }
+
+    #[inline]
+    fn bounds(&self) -> Option<Bounds<I>> {
+        Some(self.bounds)
+    }
+}
+
+unsafe impl<I, Order> UniqueIndexList for MultiRange<I, Order>
+where
+    I: Unravel + Send + Sync,
+    Order: IndexOrder,
+{
+}
+
+unsafe impl<I: Unravel + Send + Sync> IndexList for Bounds<I> {
+    type Index = I;
+
+    const ALWAYS_BOUNDED: bool = true;
+
+    #[inline]
+    unsafe fn get_index_unchecked(&self, loc: usize) -> I {
+        RowMajor::unravel(loc, self.extent).add_offset(self.offset)
+    }
+
+    #[inline]
+    fn num_indices(&self) -> usize {
+        I::checked_volume(self.extent).expect("number of indices in bounds must fit in usize")
+    }
+
+    #[inline]
+    fn bounds(&self) -> Option<Bounds<I>> {
+        Some(*self)
+    }
+
+    #[inline]
+    fn contiguous_bounds(&self) -> Option<Bounds<I>> {
+        Some(*self)
+    }
+}
+
+unsafe impl<I: Unravel + Send + Sync> UniqueIndexList for Bounds<I> {}
@@ -0,0 +1,126 @@
+//! Shared machinery for space-filling curves over boxes of arbitrary extents.
+//!
+//! A curve is described top-down on a cube whose side length is a power of two: the cube is
+//! split into `2^D` child cubes, which the curve visits in some order, each with a
+//! transformed copy of the curve. Restricting the curve to a box inside the cube means
+//! skipping the cells outside the box. The index at a given location can then be found
+//! by descending into the child cubes, counting how many cells of the box each child
+//! contains, which takes `O(2^D log n)` time.
+
+use crate::index::patterns::GridIndex;
+use paradis_core::Bounds;
+
+/// A space-filling curve on a cube whose side length is a power of two, described top-down.
+pub(crate) trait Curve<const D: usize> {
+    /// The orientation of the curve inside a (child) cube.
+    type State: Copy;
+
+    /// The orientation of the curve inside the outermost cube.
+    fn initial_state() -> Self::State;
+
+    /// Determine the `w`-th child cube visited by the curve in the given orientation.
+    ///
+    /// Returns the position of the child cube, as a bit mask in which bit `j` is set if the
+    /// child lies in the upper half along axis `j`, together with the orientation of the
+    /// curve inside the child.
+    fn child(state: Self::State, w: usize) -> (usize, Self::State);
+}
+
+/// The number of times a cube must be halved to reach single cells, if it is large enough
+/// to contain a box with the given extents.
+pub(crate) fn num_levels<const D: usize>(extents: [usize; D]) -> u32 {
+    let max_extent = extents.into_iter().max().unwrap_or(0);
+    usize::BITS - max_extent.saturating_sub(1).leading_zeros()
+}
+
+/// Compute the number of indices and the number of levels of a pattern for the given bounds.
+///
+/// # Panics
+///
+/// Panics if the number of indices in the bounds does not fit in `usize`.
+pub(crate) fn new_pattern_parts<I: GridIndex>(bounds: &Bounds<I>) -> (usize, u32) {
+    let num_indices =
+        I::checked_volume(bounds.extent).expect("number of indices in bounds must fit in usize");
+    (num_indices, num_levels(I::into_grid_bounds(*bounds).extent))
+}
+
+/// Find the cell at the given location along the curve, restricted to a zero-offset box
+/// with the given (non-zero) extents.
+///
+/// The location must be smaller than the number of cells in the box.
+#[inline]
+pub(crate) fn unrank<C: Curve<D>, const D: usize>(
+    mut loc: usize,
+    extents: [usize; D],
+    levels: u32,
+) -> [usize; D] {
+    let mut origin = [0; D];
+    let mut state = C::initial_state();
+    for level in (0..levels).rev() {
+        let half = 1 << level;
+        for w in 0..1 << D {
+            let (position, child_state) = C::child(state, w);
+            let mut child_origin = origin;
+            let mut count = 1;
+            for j in 0..D {
+                if (position >> j) & 1 == 1 {
+                    child_origin[j] += half;
+                }
+                count *= extents[j].saturating_sub(child_origin[j]).min(half);
+            }
+            if loc < count {
+                origin = child_origin;
+                state = child_state;
+                break;
+            }
+            loc -= count;
+        }
+    }
+    origin
+}
+
+/// Implements construction, [`IndexList`](crate::index::IndexList) and
+/// [`UniqueIndexList`](crate::index::UniqueIndexList) for a pattern that visits the
+/// indices in 2-D or 3-D bounds along a space-filling curve.
+///
+/// The pattern must be a struct with fields `bounds`, `num_indices` and `levels`, constructed
+/// with [`new_pattern_parts`].
+/// The invoking module must import the items used by the macro.
+macro_rules! impl_space_filling_pattern {
+    ($pattern:ty, $curve:ty, $dim:literal, ($($idx:tt),*)) => {
+        unsafe impl IndexList for $pattern {
+            type Index = ($(replace_with_usize!($idx)),*);
+
+            const ALWAYS_BOUNDED: bool = true;
+
+            #[inline]
+            unsafe fn get_index_unchecked(&self, loc: usize) -> Self::Index {
+                let extents = [$(self.bounds.extent.$idx),*];
+                let index = unrank::<$curve, $dim>(loc, extents, self.levels);
+                ($(self.bounds.offset.$idx + index[$idx]),*)
+            }
+
+            #[inline]
+            fn num_indices(&self) -> usize {
+                self.num_indices
+            }
+
+            #[inline]
+            fn bounds(&self) -> Option<Bounds<Self::Index>> {
+                Some(self.bounds)
+            }
+        }
+
+        // The curve visits every cell of the enclosing cube exactly once
+        unsafe impl UniqueIndexList for $pattern {}
+    };
+}
+
+macro_rules! replace_with_usize {
+    ($content:tt) => {
+        usize
+    };
+}
+
+pub(crate) use impl_space_filling_pattern;
+pub(crate) use replace_with_usize;
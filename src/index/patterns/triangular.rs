@@ -0,0 +1,291 @@
+use crate::index::{IndexList, UniqueIndexList};
+use paradis_core::Bounds;
+
+/// The indices `(i, j)` with `i <= j` in a matrix of the given shape, in row-major order.
+///
+/// Use [`strict`](Self::strict) to exclude the diagonal, i.e. to obtain the indices with
+/// `i < j`. The matrix need not be square. Obtaining an index takes `O(1)` time.
+///
+/// # Examples
+///
+/// ```
+/// use paradis::index::collect_indices;
+/// use paradis::index::patterns::UpperTriangular;
+///
+/// let indices: Vec<_> = collect_indices(UpperTriangular::new((3, 3)));
+/// assert_eq!(indices, vec![(0, 0), (0, 1), (0, 2), (1, 1), (1, 2), (2, 2)]);
+///
+/// let indices: Vec<_> = collect_indices(UpperTriangular::strict((3, 4)));
+/// assert_eq!(indices, vec![(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UpperTriangular {
+    shape: (usize, usize),
+    diagonal_offset: usize,
+    num_rows: usize,
+    num_indices: usize,
+}
+
+/// The indices `(i, j)` with `i >= j` in a matrix of the given shape, in row-major order.
+///
+/// Use [`strict`](Self::strict) to exclude the diagonal, i.e. to obtain the indices with
+/// `i > j`. The matrix need not be square. Obtaining an index takes `O(1)` time.
+///
+/// # Examples
+///
+/// ```
+/// use paradis::index::collect_indices;
+/// use paradis::index::patterns::LowerTriangular;
+///
+/// let indices: Vec<_> = collect_indices(LowerTriangular::new((3, 3)));
+/// assert_eq!(indices, vec![(0, 0), (1, 0), (1, 1), (2, 0), (2, 1), (2, 2)]);
+///
+/// let indices: Vec<_> = collect_indices(LowerTriangular::strict((4, 2)));
+/// assert_eq!(indices, vec![(1, 0), (2, 0), (2, 1), (3, 0), (3, 1)]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LowerTriangular {
+    shape: (usize, usize),
+    diagonal_offset: usize,
+    num_rows: usize,
+    num_indices: usize,
+}
+
+/// The triangular number `k (k + 1) / 2`.
+#[inline(always)]
+pub(super) fn triangular(k: u128) -> u128 {
+    k * (k + 1) / 2
+}
+
+/// The integer square root of `n`.
+///
+/// The floating-point estimate is corrected to the exact result, which avoids relying on
+/// `u128::isqrt` from recent toolchains. Near `u128::MAX`, the estimate may round up to `2^64`,
+/// whose square does not fit in `u128`.
+#[inline(always)]
+fn isqrt(n: u128) -> u128 {
+    let square_exceeds_n = |k: u128| !matches!(k.checked_mul(k), Some(square) if square <= n);
+    let mut k = (n as f64).sqrt() as u128;
+    while square_exceeds_n(k) {
+        k -= 1;
+    }
+    while !square_exceeds_n(k + 1) {
+        k += 1;
+    }
+    k
+}
+
+/// The largest `k` such that the triangular number `k (k + 1) / 2` is at most `r`.
+///
+/// `r` must be smaller than `2^127`, which holds for the number of indices of any pattern
+/// with a `usize` extent.
+#[inline(always)]
+pub(super) fn inverse_triangular(r: u128) -> u128 {
+    // isqrt(2r) overestimates the result by at most one
+    let k = isqrt(2 * r);
+    if triangular(k) > r {
+        k - 1
+    } else {
+        k
+    }
+}
+
+pub(super) fn checked_num_indices(num_indices: u128) -> usize {
+    usize::try_from(num_indices).expect("number of indices must fit in usize")
+}
+
+impl UpperTriangular {
+    /// The upper triangle of a matrix with the given shape, including the diagonal.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of indices does not fit in `usize`.
+    pub fn new(shape: (usize, usize)) -> Self {
+        Self::with_diagonal_offset(shape, 0)
+    }
+
+    /// The strict upper triangle of a matrix with the given shape, excluding the diagonal.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of indices does not fit in `usize`.
+    pub fn strict(shape: (usize, usize)) -> Self {
+        Self::with_diagonal_offset(shape, 1)
+    }
+
+    fn with_diagonal_offset(shape: (usize, usize), diagonal_offset: usize) -> Self {
+        // Row i contains the columns i + diagonal_offset .. n, so row lengths decrease by one
+        let first_row_len = shape.1.saturating_sub(diagonal_offset) as u128;
+        let num_rows = shape.0.min(shape.1.saturating_sub(diagonal_offset));
+        let num_indices = triangular(first_row_len) - triangular(first_row_len - num_rows as u128);
+        Self {
+            shape,
+            diagonal_offset,
+            num_rows,
+            num_indices: checked_num_indices(num_indices),
+        }
+    }
+
+    /// The shape of the matrix.
+    pub fn shape(&self) -> (usize, usize) {
+        self.shape
+    }
+}
+
+impl LowerTriangular {
+    /// The lower triangle of a matrix with the given shape, including the diagonal.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of indices does not fit in `usize`.
+    pub fn new(shape: (usize, usize)) -> Self {
+        Self::with_diagonal_offset(shape, 0)
+    }
+
+    /// The strict lower triangle of a matrix with the given shape, excluding the diagonal.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of indices does not fit in `usize`.
+    pub fn strict(shape: (usize, usize)) -> Self {
+        Self::with_diagonal_offset(shape, 1)
+    }
+
+    fn with_diagonal_offset(shape: (usize, usize), diagonal_offset: usize) -> Self {
+        // The t-th non-empty row is row t + diagonal_offset, and contains min(t + 1, n) columns
+        let num_rows = if shape.1 == 0 {
+            0
+        } else {
+            shape.0.saturating_sub(diagonal_offset)
+        };
+        let (r, n) = (num_rows as u128, shape.1 as u128);
+        let num_indices = if r <= n {
+            triangular(r)
+        } else {
+            triangular(n) + (r - n) * n
+        };
+        Self {
+            shape,
+            diagonal_offset,
+            num_rows,
+            num_indices: checked_num_indices(num_indices),
+        }
+    }
+
+    /// The shape of the matrix.
+    pub fn shape(&self) -> (usize, usize) {
+        self.shape
+    }
+}
+
+unsafe impl IndexList for UpperTriangular {
+    type Index = (usize, usize);
+
+    const ALWAYS_BOUNDED: bool = true;
+
+    #[inline]
+    unsafe fn get_index_unchecked(&self, loc: usize) -> (usize, usize) {
+        let first_row_len = (self.shape.1 - self.diagonal_offset) as u128;
+        // Counted from the end of the untruncated triangle, row i starts T(first_row_len - i)
+        // indices before the end, so the row follows from the number of remaining indices
+        let remaining = triangular(first_row_len) - loc as u128;
+        let k = inverse_triangular(remaining - 1) + 1;
+        let i = first_row_len - k;
+        let row_start = triangular(first_row_len) - triangular(k);
+        let j = i + self.diagonal_offset as u128 + (loc as u128 - row_start);
+        (i as usize, j as usize)
+    }
+
+    #[inline]
+    fn num_indices(&self) -> usize {
+        self.num_indices
+    }
+
+    fn bounds(&self) -> Option<Bounds<(usize, usize)>> {
+        if self.num_indices == 0 {
+            return Some(Bounds::new_empty());
+        }
+        Some(Bounds {
+            offset: (0, self.diagonal_offset),
+            extent: (self.num_rows, self.shape.1 - self.diagonal_offset),
+        })
+    }
+}
+
+unsafe impl UniqueIndexList for UpperTriangular {}
+
+unsafe impl IndexList for LowerTriangular {
+    type Index = (usize, usize);
+
+    const ALWAYS_BOUNDED: bool = true;
+
+    #[inline]
+    unsafe fn get_index_unchecked(&self, loc: usize) -> (usize, usize) {
+        let loc = loc as u128;
+        let n = self.shape.1 as u128;
+        // Rows grow by one column at a time until they span the full width
+        let triangle_width = n.min(self.num_rows as u128);
+        let (t, j) = if loc < triangular(triangle_width) {
+            let t = inverse_triangular(loc);
+            (t, loc - triangular(t))
+        } else {
+            let rest = loc - triangular(triangle_width);
+            (triangle_width + rest / n, rest % n)
+        };
+        ((t as usize) + self.diagonal_offset, j as usize)
+    }
+
+    #[inline]
+    fn num_indices(&self) -> usize {
+        self.num_indices
+    }
+
+    fn bounds(&self) -> Option<Bounds<(usize, usize)>> {
+        if self.num_indices == 0 {
+            return Some(Bounds::new_empty());
+        }
+        Some(Bounds {
+            offset: (self.diagonal_offset, 0),
+            extent: (self.num_rows, self.num_rows.min(self.shape.1)),
+        })
+    }
+}
+
+unsafe impl UniqueIndexList for LowerTriangular {}
+
+#[cfg(test)]
+mod tests {
+    use super::{inverse_triangular, isqrt, triangular};
+
+    #[test]
+    fn isqrt_exact() {
+        for k in [
+            0u128,
+            1,
+            2,
+            3,
+            1 << 20,
+            (1 << 32) - 1,
+            1 << 32,
+            (1 << 40) + 7,
+        ] {
+            assert_eq!(isqrt(k * k), k);
+            assert_eq!(isqrt(k * k + 2 * k), k);
+            assert_eq!(isqrt((k + 1) * (k + 1)), k + 1);
+        }
+        assert_eq!(isqrt(u64::MAX as u128), u32::MAX as u128);
+        assert_eq!(isqrt(u128::MAX), u64::MAX as u128);
+        assert_eq!(isqrt(u128::MAX - (1 << 65)), u64::MAX as u128 - 1);
+    }
+
+    #[test]
+    fn inverse_triangular_exact() {
+        for k in [0u128, 1, 5, 1 << 31, (1 << 33) + 3] {
+            assert_eq!(inverse_triangular(triangular(k)), k);
+            assert_eq!(inverse_triangular(triangular(k + 1) - 1), k);
+        }
+        let k = u64::MAX as u128;
+        assert_eq!(inverse_triangular(triangular(k)), k);
+        assert_eq!(inverse_triangular(triangular(k) - 1), k - 1);
+    }
+}
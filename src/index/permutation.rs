@@ -0,0 +1,284 @@
+use crate::error::{Error, NonUniqueIndex, OutOfBounds};
+use crate::index::{IndexList, UniqueIndexList};
+use paradis_core::Bounds;
+
+/// A permutation of the indices `0 .. n`, checked to contain every index exactly once.
+///
+/// As an index list, the permutation visits the indices `self[0], self[1], ...`, and its
+/// bounds are exactly `0 .. n`. Applying the permutation to a collection `x` means
+/// *gathering* from it, i.e. producing `y` with `y[i] = x[self[i]]`.
+///
+/// # Examples
+///
+/// ```
+/// use paradis::index::Permutation;
+///
+/// let p = Permutation::from_indices(vec![2, 0, 1]).unwrap();
+/// let q = p.inverse();
+/// assert_eq!(q.get_inner(), &vec![1, 2, 0]);
+/// assert_eq!(p.compose(&q), Permutation::identity(3));
+///
+/// assert!(Permutation::from_indices(vec![0, 0, 1]).is_err());
+/// assert!(Permutation::from_indices(vec![0, 3, 1]).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Permutation {
+    indices: Vec<usize>,
+}
+
+impl Permutation {
+    /// The identity permutation of `0 .. n`.
+    pub fn identity(n: usize) -> Self {
+        Self {
+            indices: (0..n).collect(),
+        }
+    }
+
+    /// Check that the provided indices are a permutation of `0 .. n`, where `n` is the
+    /// number of indices.
+    ///
+    /// This uses a bitmap with one bit per index, and takes `O(n)` time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`OutOfBounds`] error if an index is not smaller than `n`, and a
+    /// [`NonUniqueIndex`] error if an index occurs more than once.
+    pub fn from_indices(indices: Vec<usize>) -> Result<Self, Error<usize>> {
+        let n = indices.len();
+        let mut bitmap = vec![0u64; n.div_ceil(64)];
+        for (loc, &index) in indices.iter().enumerate() {
+            if index >= n {
+                let mut index_bounds = Bounds::new_empty();
+                for &index in &indices {
+                    index_bounds.enclose_index(index);
+                }
+                return Err(OutOfBounds {
+                    index_bounds,
+                    access_bounds: Bounds {
+                        offset: 0,
+                        extent: n,
+                    },
+                    offending_index: Some((loc, index)),
+                }
+                .into());
+            }
+            let (word, bit) = (index / 64, 1 << (index % 64));
+            if bitmap[word] & bit != 0 {
+                return Err(NonUniqueIndex::locate(&indices, index).into());
+            }
+            bitmap[word] |= bit;
+        }
+        Ok(Self { indices })
+    }
+
+    /// Obtain a reference to the underlying indices.
+    pub fn get_inner(&self) -> &Vec<usize> {
+        &self.indices
+    }
+
+    /// Recover the underlying indices.
+    pub fn into_inner(self) -> Vec<usize> {
+        self.indices
+    }
+
+    /// The inverse permutation `q`, which satisfies `q[self[i]] = i`.
+    ///
+    /// Gathering with the inverse undoes gathering with this permutation.
+    pub fn inverse(&self) -> Self {
+        let mut inverse = vec![0; self.indices.len()];
+        for (loc, &index) in self.indices.iter().enumerate() {
+            inverse[index] = loc;
+        }
+        Self { indices: inverse }
+    }
+
+    /// The composition `c` with `c[i] = self[other[i]]`.
+    ///
+    /// Gathering with the composition is the same as gathering with this permutation and
+    /// then with `other`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the permutations have different lengths.
+    pub fn compose(&self, other: &Permutation) -> Self {
+        assert_eq!(
+            self.indices.len(),
+            other.indices.len(),
+            "permutations must have the same length"
+        );
+        let indices = other
+            .indices
+            .iter()
+            .map(|&index| self.indices[index])
+            .collect();
+        Self { indices }
+    }
+}
+
+#[cfg(feature = "rayon")]
+mod par {
+    use super::Permutation;
+    use crate::error::LengthMismatch;
+    use crate::index::NarrowedAccess;
+    use crate::rayon::create_par_iter;
+    use paradis_core::{IntoParAccess, LinearParAccess, ParAccess};
+    use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+    use rayon::slice::ParallelSlice;
+    use std::ops::{Deref, DerefMut};
+
+    impl Permutation {
+        /// Gather the records of `source` into `target` in parallel, such that
+        /// `target[i]` becomes a clone of `source[self[i]]`.
+        ///
+        /// # Errors
+        ///
+        /// Returns a [`LengthMismatch`] error if the length of `source` or `target` differs
+        /// from the length of the permutation.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use paradis::index::Permutation;
+        /// use paradis::slice::SliceParAccess;
+        ///
+        /// let p = Permutation::from_indices(vec![2, 0, 1]).unwrap();
+        /// let source = vec!['a', 'b', 'c'];
+        /// let mut target = vec![' '; 3];
+        /// p.apply_into(SliceParAccess::from_slice(&source), target.as_mut_slice())
+        ///     .unwrap();
+        /// assert_eq!(target, vec!['c', 'a', 'b']);
+        /// ```
+        #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+        pub fn apply_into<Source, Target, T>(
+            &self,
+            source: Source,
+            target: Target,
+        ) -> Result<(), LengthMismatch>
+        where
+            Source: IntoParAccess,
+            Source::Access: LinearParAccess,
+            <Source::Access as ParAccess<usize>>::Record: Deref<Target = T>,
+            Target: IntoParAccess,
+            Target::Access: LinearParAccess,
+            <Target::Access as ParAccess<usize>>::Record: DerefMut<Target = T>,
+            T: Clone,
+        {
+            let (source, target) = (source.into_par_access(), target.into_par_access());
+            self.check_len(source.collection_len())?;
+            self.check_len(target.collection_len())?;
+            let source = NarrowedAccess::try_new(self, source)
+                .expect("permutation must be contained in a collection of the same length");
+            create_par_iter(target)
+                .zip(create_par_iter(source))
+                .for_each(|(mut target, source)| *target = (*source).clone());
+            Ok(())
+        }
+
+        /// Permute the records of a collection in place, such that the record at `i` is
+        /// replaced by the record previously at `self[i]`.
+        ///
+        /// The cycles of the permutation are processed in parallel, while the records within
+        /// each cycle are moved sequentially. Finding the cycles takes `O(n)` time and memory.
+        ///
+        /// # Errors
+        ///
+        /// Returns a [`LengthMismatch`] error if the length of the collection differs from
+        /// the length of the permutation.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use paradis::index::Permutation;
+        ///
+        /// let p = Permutation::from_indices(vec![2, 0, 1, 4, 3]).unwrap();
+        /// let mut data = vec!['a', 'b', 'c', 'd', 'e'];
+        /// p.apply_in_place(data.as_mut_slice()).unwrap();
+        /// assert_eq!(data, vec!['c', 'a', 'b', 'e', 'd']);
+        /// ```
+        #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+        pub fn apply_in_place<IntoAccess, T>(
+            &self,
+            access: IntoAccess,
+        ) -> Result<(), LengthMismatch>
+        where
+            IntoAccess: IntoParAccess,
+            IntoAccess::Access: LinearParAccess,
+            <IntoAccess::Access as ParAccess<usize>>::Record: DerefMut<Target = T>,
+        {
+            let access = access.into_par_access();
+            self.check_len(access.collection_len())?;
+
+            // Store the cycles of length at least two one after another, delimited by offsets
+            let n = self.indices.len();
+            let mut visited = vec![false; n];
+            let mut cycles = Vec::with_capacity(n);
+            let mut offsets = vec![0];
+            for start in 0..n {
+                if visited[start] || self.indices[start] == start {
+                    continue;
+                }
+                let mut loc = start;
+                while !visited[loc] {
+                    visited[loc] = true;
+                    cycles.push(loc);
+                    loc = self.indices[loc];
+                }
+                offsets.push(cycles.len());
+            }
+
+            offsets.par_windows(2).for_each(|window| {
+                let cycle = &cycles[window[0]..window[1]];
+                // Swapping along the cycle moves the record at cycle[j + 1] to cycle[j],
+                // and the record at cycle[0] to the end of the cycle
+                for pair in cycle.windows(2) {
+                    // SAFETY: The cycles are disjoint, so no other thread accesses the records
+                    // of this cycle, and the two locations in a cycle are distinct. Both are in
+                    // bounds, since the permutation has the same length as the collection
+                    unsafe {
+                        let mut first = access.get_unsync_unchecked(pair[0]);
+                        let mut second = access.get_unsync_unchecked(pair[1]);
+                        std::mem::swap(&mut *first, &mut *second);
+                    }
+                }
+            });
+            Ok(())
+        }
+
+        fn check_len(&self, len: usize) -> Result<(), LengthMismatch> {
+            if len == self.indices.len() {
+                Ok(())
+            } else {
+                Err(LengthMismatch {
+                    first: self.indices.len(),
+                    second: len,
+                })
+            }
+        }
+    }
+}
+
+unsafe impl IndexList for Permutation {
+    type Index = usize;
+
+    const ALWAYS_BOUNDED: bool = true;
+
+    #[inline(always)]
+    unsafe fn get_index_unchecked(&self, loc: usize) -> usize {
+        unsafe { *self.indices.get_unchecked(loc) }
+    }
+
+    #[inline(always)]
+    fn num_indices(&self) -> usize {
+        self.indices.len()
+    }
+
+    fn bounds(&self) -> Option<Bounds<usize>> {
+        Some(Bounds {
+            offset: 0,
+            extent: self.indices.len(),
+        })
+    }
+}
+
+/// Every index in `0 .. n` occurs exactly once, as checked on construction.
+unsafe impl UniqueIndexList for Permutation {}
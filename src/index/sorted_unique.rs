@@ -0,0 +1,151 @@
+use crate::error::{NonUniqueIndex, NotStrictlyIncreasing};
+use crate::index::{IndexList, UniqueIndexList};
+use crate::RecordIndex;
+use paradis_core::Bounds;
+use std::cmp::Ordering;
+
+/// A list of indices that are checked to be sorted and unique.
+///
+/// Since the indices are sorted, membership and position lookups take `O(log n)` time.
+/// See [`IndexList::check_unique_sorted`] and [`IndexList::sort_and_check_unique`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SortedUnique<Indices: IndexList> {
+    indices: Indices,
+    bounds: Bounds<Indices::Index>,
+}
+
+impl<Indices: IndexList> SortedUnique<Indices> {
+    /// Obtain a reference to the underlying index list.
+    pub fn get_inner(&self) -> &Indices {
+        &self.indices
+    }
+
+    /// Recover the underlying index list.
+    pub fn into_inner(self) -> Indices {
+        self.indices
+    }
+}
+
+impl<Indices> SortedUnique<Indices>
+where
+    Indices: IndexList,
+    Indices::Index: RecordIndex,
+{
+    /// Check that the provided indices are strictly increasing.
+    ///
+    /// On success, wrap this object in [`SortedUnique`]. The bounds of the index list
+    /// are computed at the same time, in a single linear pass.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the indices are not strictly increasing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the extent of the bounds of the indices does not fit in the index type.
+    pub fn from_sorted_indices(indices: Indices) -> Result<Self, NotStrictlyIncreasing> {
+        let n = indices.num_indices();
+        if n == 0 {
+            return Ok(Self {
+                indices,
+                bounds: Indices::Index::empty_bounds(),
+            });
+        }
+
+        let mut prev = indices.get_index(0);
+        let mut bounds = Bounds::bounds_for_index(prev);
+        for loc in 1..n {
+            let idx = indices.get_index(loc);
+            if idx <= prev {
+                return Err(NotStrictlyIncreasing { position: loc });
+            }
+            bounds.enclose_index(idx);
+            prev = idx;
+        }
+
+        Ok(Self { indices, bounds })
+    }
+
+    /// Determine the location of the given index in the list, if it is present.
+    ///
+    /// This is a binary search, taking `O(log n)` time.
+    pub fn position(&self, index: Indices::Index) -> Option<usize> {
+        if !self.bounds.contains_index(index) {
+            return None;
+        }
+
+        let (mut lower, mut upper) = (0, self.indices.num_indices());
+        while lower < upper {
+            let mid = lower + (upper - lower) / 2;
+            match self.indices.get_index(mid).cmp(&index) {
+                Ordering::Less => lower = mid + 1,
+                Ordering::Greater => upper = mid,
+                Ordering::Equal => return Some(mid),
+            }
+        }
+        None
+    }
+
+    /// Determine if the given index is contained in the list.
+    ///
+    /// This is a binary search, taking `O(log n)` time.
+    pub fn contains(&self, index: Indices::Index) -> bool {
+        self.position(index).is_some()
+    }
+}
+
+impl<I: RecordIndex> SortedUnique<Vec<I>> {
+    /// Sort a copy of the provided indices, and check that they are unique.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the indices are not unique.
+    pub fn from_unsorted_indices<Indices>(indices: Indices) -> Result<Self, NonUniqueIndex<I>>
+    where
+        Indices: IndexList<Index = I>,
+    {
+        let mut sorted: Vec<_> = (0..indices.num_indices())
+            .map(|loc| indices.get_index(loc))
+            .collect();
+        sorted.sort_unstable();
+        if let Some(pair) = sorted.windows(2).find(|pair| pair[0] == pair[1]) {
+            return Err(NonUniqueIndex::locate(&indices, pair[0]));
+        }
+        Ok(Self::from_sorted_indices(sorted)
+            .expect("sorted indices without duplicates must be strictly increasing"))
+    }
+}
+
+unsafe impl<Indices> IndexList for SortedUnique<Indices>
+where
+    Indices: IndexList,
+    Indices::Index: RecordIndex,
+{
+    type Index = Indices::Index;
+
+    const ALWAYS_BOUNDED: bool = true;
+
+    unsafe fn get_index_unchecked(&self, loc: usize) -> Self::Index {
+        unsafe { self.indices.get_index_unchecked(loc) }
+    }
+
+    fn num_indices(&self) -> usize {
+        self.indices.num_indices()
+    }
+
+    fn bounds(&self) -> Option<Bounds<Self::Index>> {
+        Some(self.bounds)
+    }
+
+    fn contiguous_bounds(&self) -> Option<Bounds<Self::Index>> {
+        // Unique indices that fill their bounds, sorted lexicographically, are in row-major order
+        (self.bounds.volume() == Some(self.num_indices())).then_some(self.bounds)
+    }
+}
+
+unsafe impl<Indices> UniqueIndexList for SortedUnique<Indices>
+where
+    Indices: IndexList,
+    Indices::Index: RecordIndex,
+{
+}
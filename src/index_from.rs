@@ -27,7 +27,7 @@ impl IndexFrom<u32> for usize {
     }
 }
 
-#[cfg(any(target_pointer_width = "64"))]
+#[cfg(target_pointer_width = "64")]
 impl IndexFrom<u64> for usize {
     fn index_from(source: u64) -> Self {
         source
@@ -38,7 +38,7 @@ impl<Access: LinearParAccess> AccessIterator<Access> {
     /// Panics if the range is out of bounds with respect to the collection.
     pub fn new_for_range(access: Access, range: Range<usize>) -> Self {
         // if end < start, then the range is empty, so account for this
-        let end = max(range.start + 1, range.end);
+        let end = max(range.start, range.end);
         assert!(
             end <= access.collection_len(),
             "range must be in bounds of collection"
@@ -68,7 +68,8 @@ where
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (self.end_idx, Some(self.end_idx))
+        let len = self.end_idx - self.next_idx;
+        (len, Some(len))
     }
 }
 
@@ -88,6 +89,6 @@ where
 
 impl<Access: LinearParAccess> ExactSizeIterator for AccessIterator<Access> {
     fn len(&self) -> usize {
-        self.end_idx
+        self.end_idx - self.next_idx
     }
 }
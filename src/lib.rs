@@ -182,11 +182,14 @@
 //! you may need to use [`index_flatten`](crate::index::IndexList::index_flatten)
 //! when working with indices of three or more dimensions.
 //!
-//! There are however significant limitations in the patterns that can be currently expressed.
-//! For example, imagine that we wanted to mutate over the first superdiagonal *and*
-//! the first subdiagonal in the previous example. Simply concatenating lists together
-//! does not preserve uniqueness, and so it is not quite clear how to do so in a fashion
-//! that avoids runtime checking or an `unsafe` escape hatch.
+//! Concatenating lists together does not in general preserve uniqueness. For example,
+//! imagine that we wanted to mutate over the first superdiagonal *and* the first subdiagonal
+//! in the previous example. We can concatenate the two lists with
+//! [`index_chain`](crate::index::IndexList::index_chain), but we must then check that the
+//! two lists are disjoint. This check is cheap when the bounds of the two lists do not overlap,
+//! but in this case the bounds overlap, and every index must be checked at runtime.
+//! There are still significant limitations in the patterns that can be expressed without
+//! runtime checking or an `unsafe` escape hatch.
 //!
 //! The vision for `paradis` is for the set of structured index lists that can be expressed
 //! with safe combinators to expand over time, in order to cover more use cases.
@@ -200,13 +203,18 @@ pub mod index;
 pub mod iter;
 #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
 #[cfg(feature = "rayon")]
+pub mod ops;
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+#[cfg(feature = "rayon")]
 pub mod rayon;
 
 mod index_from;
 
+pub use error::Error;
 pub use index_from::IndexFrom;
 pub use paradis_core::{
     slice, BoundedParAccess, Bounds, IntoParAccess, LinearParAccess, ParAccess, RecordIndex,
+    SharedParAccess,
 };
 
 mod internal {
@@ -0,0 +1,222 @@
+//! Parallel operations that move values between collections and index lists.
+//!
+#![cfg_attr(
+    not(docsrs),
+    doc = "**Only available when feature `rayon` is enabled.**\n"
+)]
+use crate::error::{Error, LengthMismatch, OutOfBounds};
+use crate::index::{IndexList, NarrowedAccess, UniqueIndexList};
+use crate::rayon::create_par_iter;
+use paradis_core::{
+    BoundedParAccess, Bounds, IntoParAccess, LinearParAccess, ParAccess, RecordIndex,
+};
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+use std::ops::{Deref, DerefMut};
+
+/// Writes `values[k]` to the record at `indices[k]` for every location `k`, in parallel.
+///
+/// The values may be any indexed parallel iterator, or anything that can be turned into one,
+/// such as a `Vec`. To scatter from a slice, use for example `slice.par_iter().copied()`.
+///
+/// Lengths and bounds are checked before any record is written. Ranges and other index lists
+/// with known bounds are checked in `O(1)` time, and their records are then obtained without
+/// further bounds checks. Index lists without bounds are instead checked index by index in
+/// parallel. For ranges and other lists that enumerate their bounds completely (see
+/// [`IndexList::contiguous_bounds`]), each index is computed from its location directly, so
+/// that a range of a slice is written without looking up the indices in the list.
+///
+/// # Errors
+///
+/// Returns a [`LengthMismatch`] error if the number of values differs from the number of
+/// indices, and an [`OutOfBounds`] error if the indices are not contained in the bounds of
+/// the collection. In both cases, no records are written.
+///
+/// # Examples
+///
+/// ```
+/// use paradis::index::IndexList;
+/// use paradis::ops::scatter;
+///
+/// let mut data = vec![0; 6];
+/// let indices = vec![4, 0, 2].check_unique().unwrap();
+/// scatter(vec![1, 2, 3], &indices, data.as_mut_slice()).unwrap();
+/// assert_eq!(data, vec![2, 0, 3, 0, 1, 0]);
+///
+/// // Any indexed parallel iterator can provide the values
+/// use rayon::iter::{IntoParallelIterator, ParallelIterator};
+/// scatter((0 .. 3).into_par_iter().map(|k| 10 * k), &(3 .. 6), data.as_mut_slice()).unwrap();
+/// assert_eq!(data, vec![2, 0, 3, 0, 10, 20]);
+///
+/// assert!(scatter(vec![1, 2], &(0 .. 3), data.as_mut_slice()).is_err());
+/// assert!(scatter(vec![1, 2], &(5 .. 7), data.as_mut_slice()).is_err());
+/// ```
+pub fn scatter<Values, Indices, IntoAccess>(
+    values: Values,
+    indices: &Indices,
+    access: IntoAccess,
+) -> Result<(), Error<Indices::Index>>
+where
+    Values: IntoParallelIterator,
+    Values::Iter: IndexedParallelIterator,
+    Indices: UniqueIndexList,
+    Indices::Index: RecordIndex,
+    IntoAccess: IntoParAccess<Indices::Index>,
+    <IntoAccess::Access as ParAccess<Indices::Index>>::Record: DerefMut<Target = Values::Item>,
+{
+    let values = values.into_par_iter();
+    check_len(indices.num_indices(), values.len())?;
+    let access = access.into_par_access();
+    if let Some(bounds) = indices
+        .contiguous_bounds()
+        .filter(|bounds| access.bounds().contains_bounds(bounds))
+    {
+        values.enumerate().for_each(|(loc, value)| {
+            // SAFETY: The bounds contain exactly the indices in the list, which are unique,
+            // and are contained in the bounds of the access
+            let mut record =
+                unsafe { access.get_unsync_unchecked(bounds.index_at_linear_offset(loc)) };
+            *record = value;
+        });
+        return Ok(());
+    }
+    let narrowed = narrow_checked(indices, access)?;
+    create_par_iter(narrowed)
+        .zip(values)
+        .for_each(|(mut record, value)| *record = value);
+    Ok(())
+}
+
+/// Collects clones of the records of a linear access into a new `Vec`, in parallel.
+///
+/// To gather the records at a list of indices, narrow the access first. Unique indices are
+/// narrowed with [`narrow_access`](crate::index::narrow_access). Indices that may be repeated
+/// are narrowed with [`narrow_shared_access`](crate::index::narrow_shared_access), which
+/// requires a read-only access such as [`SliceParAccess`](crate::slice::SliceParAccess).
+///
+/// # Examples
+///
+/// ```
+/// use paradis::index::{narrow_access, narrow_shared_access, IndexList};
+/// use paradis::ops::gather;
+/// use paradis::slice::SliceParAccess;
+///
+/// let mut data = vec![10, 11, 12, 13, 14];
+/// let indices = vec![4, 0, 2].check_unique().unwrap();
+/// let access = narrow_access(data.as_mut_slice(), &indices).unwrap();
+/// assert_eq!(gather(access), vec![14, 10, 12]);
+///
+/// let indices = vec![2, 0, 2, 2];
+/// let access = narrow_shared_access(SliceParAccess::from_slice(&data), &indices).unwrap();
+/// assert_eq!(gather(access), vec![12, 10, 12, 12]);
+/// ```
+pub fn gather<IntoAccess, T>(access: IntoAccess) -> Vec<T>
+where
+    IntoAccess: IntoParAccess,
+    IntoAccess::Access: LinearParAccess,
+    <IntoAccess::Access as ParAccess<usize>>::Record: Deref<Target = T>,
+    T: Clone + Send,
+{
+    let mut gathered = Vec::new();
+    create_par_iter(access)
+        .map(|record| (*record).clone())
+        .collect_into_vec(&mut gathered);
+    gathered
+}
+
+/// Writes clones of the records of a linear access to `target`, in parallel.
+///
+/// As for [`gather`], narrow the access first to gather the records at a list of indices.
+///
+/// # Errors
+///
+/// Returns a [`LengthMismatch`] error if the length of `target` differs from the length of the
+/// collection. In this case, `target` is left unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use paradis::index::narrow_access;
+/// use paradis::ops::gather_into;
+///
+/// let mut data = vec![10, 11, 12, 13, 14];
+/// let mut target = vec![0; 3];
+/// let access = narrow_access(data.as_mut_slice(), &(1 ..= 3)).unwrap();
+/// gather_into(access, &mut target).unwrap();
+/// assert_eq!(target, vec![11, 12, 13]);
+///
+/// let access = narrow_access(data.as_mut_slice(), &(0 .. 2)).unwrap();
+/// assert!(gather_into(access, &mut target).is_err());
+/// ```
+pub fn gather_into<IntoAccess, T>(
+    access: IntoAccess,
+    target: &mut [T],
+) -> Result<(), LengthMismatch>
+where
+    IntoAccess: IntoParAccess,
+    IntoAccess::Access: LinearParAccess,
+    <IntoAccess::Access as ParAccess<usize>>::Record: Deref<Target = T>,
+    T: Clone + Send,
+{
+    let access = access.into_par_access();
+    check_len(access.collection_len(), target.len())?;
+    create_par_iter(target)
+        .zip(create_par_iter(access))
+        .for_each(|(target, record)| *target = (*record).clone());
+    Ok(())
+}
+
+fn check_len(num_indices: usize, len: usize) -> Result<(), LengthMismatch> {
+    if num_indices == len {
+        Ok(())
+    } else {
+        Err(LengthMismatch {
+            first: num_indices,
+            second: len,
+        })
+    }
+}
+
+/// Narrow an access, checking every index up front if the indices have no bounds.
+fn narrow_checked<Indices, Access>(
+    indices: &Indices,
+    access: Access,
+) -> Result<NarrowedAccess<'_, Indices, Access>, OutOfBounds<Indices::Index>>
+where
+    Indices: IndexList,
+    Indices::Index: RecordIndex,
+    Access: BoundedParAccess<Indices::Index>,
+{
+    if indices.bounds().is_none() {
+        check_each_in_bounds(indices, access.bounds())?;
+    }
+    NarrowedAccess::try_new(indices, access)
+}
+
+/// Check every index of a list without bounds against the bounds of an access.
+fn check_each_in_bounds<Indices>(
+    indices: &Indices,
+    access_bounds: Bounds<Indices::Index>,
+) -> Result<(), OutOfBounds<Indices::Index>>
+where
+    Indices: IndexList,
+    Indices::Index: RecordIndex,
+{
+    let offending_index = (0..indices.num_indices())
+        .into_par_iter()
+        .map(|loc| (loc, indices.get_index(loc)))
+        .find_first(|&(_, index)| !access_bounds.contains_index(index));
+    match offending_index {
+        None => Ok(()),
+        Some(offending_index) => {
+            let mut index_bounds = Bounds::new_empty();
+            for loc in 0..indices.num_indices() {
+                index_bounds.enclose_index(indices.get_index(loc));
+            }
+            Err(OutOfBounds {
+                index_bounds,
+                access_bounds,
+                offending_index: Some(offending_index),
+            })
+        }
+    }
+}
@@ -4,9 +4,11 @@
     not(docsrs),
     doc = "**Only available when feature `rayon` is enabled.**\n"
 )]
+use crate::error::OutOfBounds;
+use crate::index::{DisjointClasses, NarrowedAccess, UniqueIndexList};
 use crate::iter::AccessIterator;
 use crate::IntoParAccess;
-use paradis_core::LinearParAccess;
+use paradis_core::{LinearParAccess, ParAccess, RecordIndex};
 use rayon::iter::plumbing::{bridge, Consumer, Producer, ProducerCallback, UnindexedConsumer};
 use rayon::iter::{IndexedParallelIterator, ParallelIterator};
 
@@ -39,6 +41,70 @@ where
     LinearParAccessIter::from_access(access)
 }
 
+/// Applies a function to the records at the given colour classes of indices, processing the
+/// colours one after another and the records of each colour in parallel.
+///
+/// This is the usual pattern for colourings such as
+/// [`Checkerboard`](crate::index::patterns::Checkerboard) or
+/// [`Coloring`](crate::index::coloring::Coloring): the indices within each colour class are
+/// unique, so the records of a colour can be processed in parallel, whereas different colours
+/// are processed in sequence.
+///
+/// Records may outlive the call to `f`, so a record obtained for one colour must not alias a
+/// record obtained for another. The classes are therefore passed as [`DisjointClasses`], which
+/// guarantees that they are pairwise disjoint. Colour classes from
+/// [`Checkerboard::colors`](crate::index::patterns::Checkerboard::colors) and
+/// [`Coloring::classes`](crate::index::coloring::Coloring::classes) are disjoint by construction,
+/// and other classes can be checked once with [`DisjointClasses::par_from_classes`] and then
+/// reused for any number of sweeps. Only the bounds of the colour classes are checked here,
+/// before any record is processed. The access object is obtained only once and shared between
+/// the colours.
+///
+/// # Errors
+///
+/// Returns an [`OutOfBounds`] error for the first colour class whose bounds are not contained
+/// in the bounds of the collection. In this case, no records are processed.
+///
+/// # Examples
+///
+/// ```
+/// use paradis::index::patterns::Checkerboard;
+/// use paradis::rayon::for_each_colored;
+/// use paradis::Bounds;
+/// use paradis_demo::DMatrixParAccessMut;
+///
+/// let mut matrix = nalgebra::DMatrix::<u32>::zeros(3, 4);
+/// let bounds = Bounds { offset: (0, 0), extent: (3, 4) };
+/// let colors = Checkerboard::colors(bounds, 2);
+/// let access = DMatrixParAccessMut::from_matrix_mut(&mut matrix);
+/// for_each_colored(access, &colors, |entry| *entry += 1).unwrap();
+/// assert!(matrix.iter().all(|&entry| entry == 1));
+/// ```
+pub fn for_each_colored<IntoAccess, Indices, F>(
+    access: IntoAccess,
+    colors: &DisjointClasses<Indices>,
+    f: F,
+) -> Result<(), OutOfBounds<Indices::Index>>
+where
+    IntoAccess: IntoParAccess<Indices::Index>,
+    Indices: UniqueIndexList,
+    Indices::Index: RecordIndex,
+    F: Fn(<IntoAccess::Access as ParAccess<Indices::Index>>::Record) + Sync + Send,
+{
+    let access = access.into_par_access();
+    // SAFETY: The indices within each colour are unique, and the colours are pairwise disjoint.
+    // The accesses therefore never obtain the same record twice, even if records outlive the
+    // call to `f`
+    let narrowed_accesses = colors
+        .iter()
+        .map(|indices| NarrowedAccess::try_new(indices, unsafe { access.clone_access() }))
+        .collect::<Result<Vec<_>, _>>()?;
+    for narrowed in narrowed_accesses {
+        create_par_iter(narrowed).for_each(&f);
+    }
+    Ok(())
+}
+
 struct AccessProducer<Access> {
     access: Access,
     start_idx: usize,
@@ -57,7 +123,7 @@ where
     }
 
     fn split_at(self, index: usize) -> (Self, Self) {
-        debug_assert!(index < (self.end_idx - self.start_idx));
+        debug_assert!(index <= (self.end_idx - self.start_idx));
         // SAFETY: The two producers both obtain unsyncrhonized access to the underlying data structure,
         // but they work on non-overlapping index sets
         let left = Self {
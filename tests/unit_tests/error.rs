@@ -0,0 +1,96 @@
+use paradis::error::NonUniqueIndex;
+use paradis::index::{collect_indices, narrow_access, CheckedUnique, IndexList, SortedUnique};
+use paradis::{Bounds, Error};
+
+#[test]
+fn non_unique_index_reports_positions() {
+    let indices: Vec<(usize, usize)> = vec![(0, 0), (3, 7), (1, 2), (3, 7), (1, 2)];
+    let err = CheckedUnique::from_hashable_indices(indices.clone()).unwrap_err();
+    assert_eq!(err.to_string(), "index (3, 7) occurs at positions 1 and 3");
+
+    // Every strategy reports the first two occurrences of some duplicated index
+    for err in [
+        CheckedUnique::from_indices(indices.clone()).unwrap_err(),
+        CheckedUnique::from_indices_with_sorting(indices.clone()).unwrap_err(),
+        CheckedUnique::from_indices_with_bitmap(indices.clone()).unwrap_err(),
+        SortedUnique::from_unsorted_indices(indices.clone()).unwrap_err(),
+        #[cfg(feature = "rayon")]
+        indices.clone().par_check_unique().unwrap_err(),
+    ] {
+        let NonUniqueIndex {
+            index,
+            first,
+            second,
+            ..
+        } = err;
+        assert!(first < second);
+        assert_eq!(indices[first], index);
+        assert_eq!(indices[second], index);
+        assert!(!indices[..first].contains(&index));
+        assert!(!indices[first + 1..second].contains(&index));
+    }
+
+    // More indices than fit in the bounds
+    let err = vec![2usize, 3, 2, 3].check_unique().unwrap_err();
+    assert_eq!((err.index, err.first, err.second), (2, 0, 2));
+
+    // Overlapping chains
+    let err = (0..4).index_chain(2..6).check_disjoint().unwrap_err();
+    assert_eq!((err.index, err.first, err.second), (2, 2, 4));
+}
+
+#[test]
+fn out_of_bounds_reports_bounds() {
+    let mut data = vec![0; 5];
+    let indices = vec![1usize, 6, 2].check_unique().unwrap();
+    let err = narrow_access(data.as_mut_slice(), &indices).unwrap_err();
+    assert_eq!(
+        err.index_bounds,
+        Bounds {
+            offset: 1,
+            extent: 6
+        }
+    );
+    assert_eq!(
+        err.access_bounds,
+        Bounds {
+            offset: 0,
+            extent: 5
+        }
+    );
+    assert_eq!(err.offending_index, Some((1, 6)));
+    assert_eq!(
+        err.to_string(),
+        "index 6 at position 1 is out of bounds, index bounds (offset 1, extent 6) \
+         are not contained in access bounds (offset 0, extent 5)"
+    );
+}
+
+#[test]
+fn fallible_constructors() {
+    let err = (0..3).try_index_zip(0..4).unwrap_err();
+    assert_eq!((err.first, err.second), (3, 4));
+    assert!((0..3).try_index_azip(1..5).is_err());
+    let zipped: Vec<_> = collect_indices((0..2).try_index_zip(3..5).unwrap());
+    assert_eq!(zipped, vec![(0, 3), (1, 4)]);
+    let zipped: Vec<_> = collect_indices((0..2).try_index_azip(3..5).unwrap());
+    assert_eq!(zipped, vec![(0, 3), (1, 4)]);
+
+    assert!((0..usize::MAX).try_index_product(0..2).is_err());
+    assert!((0..usize::MAX).try_index_product(0..0).is_ok());
+    let product = (0..2).try_index_product(0..3).unwrap();
+    assert_eq!(product.num_indices(), 6);
+}
+
+#[test]
+fn unified_error() {
+    let err: Error<usize> = (0..3).try_index_zip(0..4).unwrap_err().into();
+    assert_eq!(
+        err.to_string(),
+        "index lists have different numbers of indices (3 and 4)"
+    );
+    let err: Error<usize> = vec![1usize, 1].check_unique().unwrap_err().into();
+    assert_eq!(err.to_string(), "index 1 occurs at positions 0 and 1");
+    let err: Error<usize> = (0..usize::MAX).try_index_product(0..2).unwrap_err().into();
+    assert!(matches!(err, Error::Overflow(_)));
+}
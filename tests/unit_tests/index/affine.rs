@@ -0,0 +1,77 @@
+use crate::unit_tests::assert_unique;
+use paradis::index::{collect_indices, IndexList};
+use paradis::Bounds;
+
+#[test]
+fn index_offset() {
+    let shifted = (2..5).index_offset(-2);
+    assert_unique(&shifted);
+    assert_eq!(collect_indices::<Vec<_>, _>(&shifted), vec![0, 1, 2]);
+    assert_eq!(
+        shifted.bounds(),
+        Some(Bounds {
+            offset: 0,
+            extent: 3
+        })
+    );
+
+    let shifted = (0..2).index_product(3..5).index_offset((1, -3));
+    assert_eq!(
+        collect_indices::<Vec<_>, _>(&shifted),
+        vec![(1, 0), (1, 1), (2, 0), (2, 1)]
+    );
+    assert_eq!(
+        shifted.bounds(),
+        Some(Bounds {
+            offset: (1, 0),
+            extent: (2, 2)
+        })
+    );
+
+    // Empty lists can always be shifted
+    assert_eq!((0..0).index_offset(-1).num_indices(), 0);
+}
+
+#[test]
+fn index_affine() {
+    let fine = (1..4).index_affine(2, 1);
+    assert_unique(&fine);
+    assert_eq!(collect_indices::<Vec<_>, _>(&fine), vec![3, 5, 7]);
+    assert_eq!(
+        fine.bounds(),
+        Some(Bounds {
+            offset: 3,
+            extent: 5
+        })
+    );
+
+    let fine = (0..2).index_product(0..2).index_affine((2, 3), (0, 1));
+    assert_eq!(
+        collect_indices::<Vec<_>, _>(&fine),
+        vec![(0, 1), (0, 4), (2, 1), (2, 4)]
+    );
+    assert_eq!(
+        fine.bounds(),
+        Some(Bounds {
+            offset: (0, 1),
+            extent: (3, 4)
+        })
+    );
+
+    // Unbounded lists are mapped without precomputed bounds
+    let mapped = vec![4usize, 1].index_affine(3, -1);
+    assert_eq!(mapped.bounds(), None);
+    assert_eq!(collect_indices::<Vec<_>, _>(&mapped), vec![11, 2]);
+}
+
+#[test]
+#[should_panic]
+fn index_offset_overflow() {
+    let _ = (0..3).index_offset(-1);
+}
+
+#[test]
+#[should_panic]
+fn index_affine_zero_scale() {
+    let _ = (0..3).index_product(0..3).index_affine((1, 0), (0, 0));
+}
@@ -0,0 +1,72 @@
+use paradis::index::{collect_indices, narrow_access, IndexList};
+use paradis::Bounds;
+
+#[test]
+fn index_chain() {
+    let chain = (0..2).index_chain(vec![7, 5]);
+    assert_eq!(chain.num_indices(), 4);
+    assert_eq!(collect_indices::<Vec<_>, _>(&chain), vec![0, 1, 7, 5]);
+    // Vec is not bounded, so neither is the chain
+    assert_eq!(chain.bounds(), None);
+
+    let chain = (0..2).index_chain(5..7);
+    assert_eq!(
+        chain.bounds(),
+        Some(Bounds {
+            offset: 0,
+            extent: 7
+        })
+    );
+}
+
+#[test]
+fn index_interleave() {
+    let interleaved = (0..2).index_interleave(10..15);
+    assert_eq!(
+        collect_indices::<Vec<_>, _>(&interleaved),
+        vec![0, 10, 1, 11, 12, 13, 14]
+    );
+
+    let interleaved = (0..4).index_interleave(10..11);
+    assert_eq!(
+        collect_indices::<Vec<_>, _>(&interleaved),
+        vec![0, 10, 1, 2, 3]
+    );
+}
+
+#[test]
+fn check_disjoint() {
+    // Disjoint bounds
+    let indices = (0..3).index_chain(5..7).check_disjoint().unwrap();
+    assert_eq!(
+        indices.bounds(),
+        Some(Bounds {
+            offset: 0,
+            extent: 7
+        })
+    );
+
+    // Overlapping bounds, but disjoint indices
+    let even = (0..10).step_by(2);
+    let odd = (1..10).step_by(2);
+    let indices = even.index_interleave(odd).check_disjoint().unwrap();
+    assert_eq!(
+        collect_indices::<Vec<_>, _>(&indices),
+        (0..10).collect::<Vec<_>>()
+    );
+
+    // Overlapping indices
+    assert!((0..3).index_chain(2..4).check_disjoint().is_err());
+    assert!((0..3).index_interleave(2..4).check_disjoint().is_err());
+
+    // Interior and boundary of a 1D grid
+    let mut data = vec![0; 6];
+    let interior = 1..5;
+    let boundary = vec![0, 5].check_unique().unwrap();
+    let indices = interior.index_chain(boundary).check_disjoint().unwrap();
+    let access = narrow_access(data.as_mut_slice(), &indices).unwrap();
+    for record in paradis::iter::create_iter(access) {
+        *record += 1;
+    }
+    assert_eq!(data, vec![1; 6]);
+}
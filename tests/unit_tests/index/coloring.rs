@@ -0,0 +1,92 @@
+use crate::unit_tests::assert_unique;
+use paradis::index::coloring::Coloring;
+use paradis::index::{collect_indices, narrow_access, IndexList};
+use paradis::iter::create_iter;
+
+/// Check that the classes partition the items, and that no two items of the same
+/// colour share a resource.
+fn assert_valid_coloring(coloring: &Coloring, items: &[Vec<usize>]) {
+    assert_eq!(coloring.num_items(), items.len());
+    let mut seen = vec![false; items.len()];
+    for (color, class) in coloring.classes().iter().enumerate() {
+        assert_unique(class);
+        assert!(class.num_indices() > 0);
+        let members: Vec<usize> = collect_indices(class.clone());
+        for &item in &members {
+            assert!(!seen[item]);
+            seen[item] = true;
+            assert_eq!(coloring.color_of(item), color);
+        }
+        for (a, &first) in members.iter().enumerate() {
+            for &second in &members[a + 1..] {
+                assert!(
+                    items[first].iter().all(|r| !items[second].contains(r)),
+                    "items {first} and {second} have colour {color} and share a resource"
+                );
+            }
+        }
+    }
+    assert!(seen.into_iter().all(|seen| seen));
+}
+
+#[test]
+fn coloring_of_structured_triangle_mesh() {
+    // Two triangles per cell of an n x n grid of cells, with (n + 1)^2 nodes
+    let n = 6;
+    let node = |i: usize, j: usize| i * (n + 1) + j;
+    let mut elements = Vec::new();
+    for i in 0..n {
+        for j in 0..n {
+            elements.push(vec![node(i, j), node(i + 1, j), node(i + 1, j + 1)]);
+            elements.push(vec![node(i, j), node(i + 1, j + 1), node(i, j + 1)]);
+        }
+    }
+    let coloring = Coloring::greedy(elements.iter().map(|e| e.iter().copied()));
+    assert_valid_coloring(&coloring, &elements);
+    // Each node is shared by at most six triangles
+    assert!(coloring.num_colors() <= 13);
+}
+
+#[test]
+fn coloring_of_irregular_items() {
+    let items = vec![
+        vec![],
+        vec![7, 7, 2],
+        vec![2],
+        vec![100],
+        vec![0, 100, 3],
+        vec![],
+        vec![3, 7],
+        vec![5],
+    ];
+    let coloring = Coloring::greedy(items.iter().map(|e| e.iter().copied()));
+    assert_valid_coloring(&coloring, &items);
+    assert_eq!(coloring.num_colors(), 3);
+    assert_eq!(coloring.class(0).get_inner(), &vec![0, 1, 3, 5, 7]);
+    assert_eq!(coloring.class(1).get_inner(), &vec![2, 4]);
+    assert_eq!(coloring.class(2).get_inner(), &vec![6]);
+}
+
+#[test]
+fn coloring_of_no_items() {
+    let coloring = Coloring::greedy(Vec::<Vec<usize>>::new());
+    assert_eq!(coloring.num_colors(), 0);
+    assert_eq!(coloring.num_items(), 0);
+    assert!(coloring.into_classes().is_empty());
+}
+
+#[test]
+fn coloring_classes_narrow_access() {
+    let elements = [[0, 1], [1, 2], [2, 3], [3, 4], [4, 0]];
+    let coloring = Coloring::greedy(elements);
+    let mut element_colors = vec![usize::MAX; elements.len()];
+    for (color, class) in coloring.classes().iter().enumerate() {
+        let access = narrow_access(element_colors.as_mut_slice(), class).unwrap();
+        for value in create_iter(access) {
+            *value = color;
+        }
+    }
+    let expected: Vec<_> = (0..elements.len()).map(|e| coloring.color_of(e)).collect();
+    assert_eq!(element_colors, expected);
+    assert_eq!(coloring.num_colors(), 3);
+}
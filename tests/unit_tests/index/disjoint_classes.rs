@@ -0,0 +1,50 @@
+use paradis::index::{DisjointClasses, IndexFn, IndexList};
+
+fn concatenate<Indices: IndexList>(classes: &[Indices]) -> Vec<Indices::Index> {
+    classes
+        .iter()
+        .flat_map(|indices| (0..indices.num_indices()).map(|loc| indices.get_index(loc)))
+        .collect()
+}
+
+#[test]
+fn disjoint_classes_from_classes() {
+    let classes = vec![3..5, 0..0, 0..3, 5..9];
+    let disjoint = DisjointClasses::from_classes(classes.clone()).unwrap();
+    assert_eq!(&*disjoint, classes.as_slice());
+    assert_eq!(disjoint.into_inner(), classes);
+
+    assert!(DisjointClasses::from_classes(Vec::<std::ops::Range<usize>>::new()).is_ok());
+    assert!(DisjointClasses::from_classes(vec![0..5, 5..5]).is_ok());
+}
+
+#[test]
+fn disjoint_classes_reject_overlapping_classes() {
+    // Sparse indices are checked by sorting, and dense indices with a bitmap
+    for (stride, start) in [(1, 2), (1 << 40, 3)] {
+        // SAFETY: The functions are deterministic and injective
+        let make = |start: usize, len: usize| unsafe {
+            IndexFn::new(len, move |loc| (start + loc) * stride).assume_injective()
+        };
+        let classes = vec![make(0, 2), make(start, 4), make(10, 3), make(7, 4)];
+        let err = DisjointClasses::from_classes(classes.clone()).unwrap_err();
+        let concatenated = concatenate(&classes);
+        assert_eq!(err.index, 10 * stride);
+        assert_eq!((err.first, err.second), (6, 12));
+        assert_eq!(concatenated[err.first], err.index);
+        assert_eq!(concatenated[err.second], err.index);
+    }
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn par_disjoint_classes_from_classes() {
+    let classes: Vec<_> = (0..8).map(|i| (100 * i)..(100 * i + 100)).collect();
+    let disjoint = DisjointClasses::par_from_classes(classes.clone()).unwrap();
+    assert_eq!(disjoint.into_inner(), classes);
+
+    let mut classes = classes;
+    classes.push(450..451);
+    let err = DisjointClasses::par_from_classes(classes).unwrap_err();
+    assert_eq!((err.index, err.first, err.second), (450, 450, 800));
+}
@@ -0,0 +1,61 @@
+use crate::unit_tests::assert_unique;
+use paradis::index::{collect_indices, narrow_access, IndexFn, IndexList};
+use paradis::iter::create_iter;
+use paradis::Bounds;
+
+#[test]
+fn index_fn() {
+    let indices = unsafe { IndexFn::new(4, |loc| 3 * loc + 1) };
+    assert_eq!(indices.num_indices(), 4);
+    assert_eq!(indices.bounds(), None);
+    assert_eq!(collect_indices::<Vec<_>, _>(&indices), vec![1, 4, 7, 10]);
+
+    let indices = unsafe {
+        indices.assume_injective().assume_bounds(Bounds {
+            offset: 1,
+            extent: 10,
+        })
+    };
+    assert_unique(&indices);
+    assert_eq!(
+        indices.bounds(),
+        Some(Bounds {
+            offset: 1,
+            extent: 10
+        })
+    );
+}
+
+#[test]
+fn index_map() {
+    let mut data = vec![0; 8];
+    let indices = unsafe { (0..4).index_map(|i| 7 - 2 * i).assume_injective() };
+    assert_unique(&indices);
+    assert_eq!(collect_indices::<Vec<_>, _>(&indices), vec![7, 5, 3, 1]);
+
+    // Without bounds, every access is checked
+    let access = narrow_access(data.as_mut_slice(), &indices).unwrap();
+    for x in create_iter(access) {
+        *x = 1;
+    }
+    assert_eq!(data, vec![0, 1, 0, 1, 0, 1, 0, 1]);
+
+    let indices = unsafe {
+        indices.assume_bounds(Bounds {
+            offset: 1,
+            extent: 7,
+        })
+    };
+    assert!(narrow_access(&mut data[..7], &indices).is_err());
+}
+
+#[test]
+#[should_panic]
+fn index_map_unbounded_out_of_bounds_access() {
+    let mut data = vec![0; 4];
+    let indices = unsafe { (0..3).index_map(|i| 2 * i).assume_injective() };
+    let access = narrow_access(data.as_mut_slice(), &indices).unwrap();
+    for x in create_iter(access) {
+        *x = 1;
+    }
+}
@@ -0,0 +1,11 @@
+mod affine;
+mod chain;
+mod coloring;
+mod disjoint_classes;
+mod index_fn;
+mod partition;
+mod permutation;
+mod product_n;
+mod ranges;
+mod slicing;
+mod tiled_product;
@@ -0,0 +1,155 @@
+use crate::unit_tests::assert_unique;
+use paradis::index::partition::{Partition, PartitionPiece};
+use paradis::index::{collect_indices, IndexList, UniqueIndexList};
+use paradis::iter::create_iter;
+use paradis::Bounds;
+use paradis_core::RecordIndex;
+use paradis_demo::DMatrixParAccessMut;
+use std::collections::HashSet;
+
+/// Check that the pieces are unique, have valid bounds, and together contain every index of
+/// the list exactly once, each piece in the relative order of the list.
+fn assert_valid_partition<Indices>(indices: &Indices, pieces: &[PartitionPiece<'_, Indices>])
+where
+    Indices: UniqueIndexList,
+    Indices::Index: RecordIndex,
+{
+    let all: Vec<_> = collect_indices(indices);
+    let mut seen = HashSet::new();
+    for piece in pieces {
+        assert_unique(piece);
+        let members: Vec<_> = collect_indices(piece);
+        let bounds = piece.bounds().unwrap();
+        for &idx in &members {
+            assert!(bounds.contains_index(idx));
+            assert!(seen.insert(idx), "index {idx:?} is in more than one piece");
+        }
+        let positions: Vec<_> = members
+            .iter()
+            .map(|idx| all.iter().position(|other| other == idx).unwrap())
+            .collect();
+        assert!(positions.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+    assert_eq!(seen.len(), all.len());
+}
+
+#[test]
+fn contiguous_partition() {
+    let indices = (0..10).step_by(3);
+    for k in 1..=6 {
+        let partition = Partition::contiguous(&indices, k);
+        assert_eq!(partition.num_pieces(), k);
+        assert_valid_partition(&indices, partition.pieces());
+        let lengths: Vec<_> = partition.pieces().iter().map(|p| p.num_indices()).collect();
+        assert!(lengths.iter().max().unwrap() - lengths.iter().min().unwrap() <= 1);
+    }
+    let partition = Partition::contiguous(&indices, 3);
+    let pieces: Vec<Vec<usize>> = partition.pieces().iter().map(collect_indices).collect();
+    assert_eq!(pieces, vec![vec![0], vec![3], vec![6, 9]]);
+}
+
+#[test]
+fn balanced_partition() {
+    let indices = 0..100usize;
+    let weight = |i: usize| if i < 10 { 10 } else { 1 };
+    for k in 1..=7 {
+        let partition = Partition::balanced(&indices, k, weight);
+        assert_eq!(partition.num_pieces(), k);
+        assert_valid_partition(&indices, partition.pieces());
+        // Each piece overshoots its share of the total weight by less than one index
+        let total: usize = indices.clone().map(weight).sum();
+        for piece in partition.pieces() {
+            let piece_weight: usize = collect_indices::<Vec<_>, _>(piece)
+                .into_iter()
+                .map(weight)
+                .sum();
+            assert!(piece_weight < total / k + 2 * 10);
+        }
+    }
+
+    let partition = Partition::balanced(&indices, 2, weight);
+    // The first ten indices weigh as much as the remaining ninety
+    assert_eq!(partition.pieces()[0].num_indices(), 10);
+}
+
+#[test]
+fn bisection_partition_2d() {
+    let bounds = Bounds {
+        offset: (2, 3),
+        extent: (13, 9),
+    };
+    for k in 1..=9 {
+        let partition = Partition::bisection(&bounds, k);
+        assert_eq!(partition.num_pieces(), k);
+        assert_valid_partition(&bounds, partition.pieces());
+        let lengths: Vec<_> = partition.pieces().iter().map(|p| p.num_indices()).collect();
+        assert!(lengths.iter().max().unwrap() - lengths.iter().min().unwrap() <= 1);
+    }
+
+    // Splitting a 4 x 8 box in four gives four 4 x 2 boxes, since the long axis is halved
+    let bounds = Bounds {
+        offset: (0, 0),
+        extent: (4, 8),
+    };
+    let partition = Partition::bisection(&bounds, 4);
+    for (p, piece) in partition.pieces().iter().enumerate() {
+        assert_eq!(
+            piece.bounds(),
+            Some(Bounds {
+                offset: (0, 2 * p),
+                extent: (4, 2),
+            })
+        );
+    }
+}
+
+#[test]
+fn bisection_partition_1d_and_3d() {
+    let indices = vec![17usize, 3, 9, 4, 12, 1, 30, 8];
+    let indices = indices.check_unique().unwrap();
+    let partition = Partition::bisection(&indices, 2);
+    assert_valid_partition(&indices, partition.pieces());
+    let pieces: Vec<Vec<usize>> = partition.pieces().iter().map(collect_indices).collect();
+    assert_eq!(pieces, vec![vec![3, 4, 1, 8], vec![17, 9, 12, 30]]);
+
+    let bounds = Bounds {
+        offset: (0, 1, 2),
+        extent: (3, 4, 5),
+    };
+    for k in [1, 2, 5, 8, 60, 70] {
+        let partition = Partition::bisection(&bounds, k);
+        assert_valid_partition(&bounds, partition.pieces());
+    }
+}
+
+#[test]
+fn partition_narrow_access_in_threads() {
+    let mut matrix = nalgebra::DMatrix::<usize>::zeros(6, 10);
+    let bounds = Bounds {
+        offset: (0, 0),
+        extent: (6, 10),
+    };
+    let partition = Partition::bisection(&bounds, 4);
+    let access = DMatrixParAccessMut::from_matrix_mut(&mut matrix);
+    let accesses = partition.narrow_access(access).unwrap();
+    std::thread::scope(|s| {
+        for (p, access) in accesses.into_iter().enumerate() {
+            s.spawn(move || {
+                for entry in create_iter(access) {
+                    *entry += p + 1;
+                }
+            });
+        }
+    });
+    for (p, piece) in partition.pieces().iter().enumerate() {
+        for idx in collect_indices::<Vec<_>, _>(piece) {
+            assert_eq!(matrix[idx], p + 1);
+        }
+    }
+    assert!(matrix.iter().all(|&entry| entry > 0));
+
+    let mut data = vec![0; 5];
+    let indices = 0..6usize;
+    let partition = Partition::contiguous(&indices, 2);
+    assert!(partition.narrow_access(data.as_mut_slice()).is_err());
+}
@@ -0,0 +1,129 @@
+use crate::unit_tests::assert_unique;
+use paradis::index::{collect_indices, IndexList, Permutation};
+#[cfg(feature = "rayon")]
+use paradis::slice::SliceParAccess;
+use paradis::Bounds;
+use paradis::Error;
+
+/// A pseudo-random permutation of `0 .. n`, obtained by a Fisher-Yates shuffle.
+fn shuffled(n: usize, seed: u64) -> Permutation {
+    let mut state = seed;
+    let mut indices: Vec<usize> = (0..n).collect();
+    for i in (1..n).rev() {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        indices.swap(i, (state >> 33) as usize % (i + 1));
+    }
+    Permutation::from_indices(indices).unwrap()
+}
+
+#[test]
+fn permutation_validation() {
+    let p = Permutation::from_indices(vec![3, 1, 0, 2]).unwrap();
+    assert_unique(&p);
+    assert_eq!(
+        p.bounds(),
+        Some(Bounds {
+            offset: 0,
+            extent: 4
+        })
+    );
+    assert_eq!(collect_indices::<Vec<_>, _>(&p), vec![3, 1, 0, 2]);
+    assert_eq!(Permutation::from_indices(vec![]).unwrap().num_indices(), 0);
+
+    match Permutation::from_indices(vec![3, 1, 4, 0]) {
+        Err(Error::OutOfBounds(err)) => assert_eq!(err.offending_index, Some((2, 4))),
+        other => panic!("expected out of bounds error, got {other:?}"),
+    }
+    match Permutation::from_indices(vec![2, 1, 0, 1]) {
+        Err(Error::NonUniqueIndex(err)) => {
+            assert_eq!((err.index, err.first, err.second), (1, 1, 3))
+        }
+        other => panic!("expected non-unique index error, got {other:?}"),
+    }
+}
+
+#[test]
+fn permutation_inverse_and_compose() {
+    for n in [0, 1, 2, 17, 200] {
+        let p = shuffled(n, 1);
+        let q = shuffled(n, 2);
+        let inverse = p.inverse();
+        assert_eq!(p.compose(&inverse), Permutation::identity(n));
+        assert_eq!(inverse.compose(&p), Permutation::identity(n));
+        assert_eq!(inverse.inverse(), p);
+
+        let composed = p.compose(&q);
+        for i in 0..n {
+            assert_eq!(composed.get_index(i), p.get_index(q.get_index(i)));
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn permutation_compose_length_mismatch_panics() {
+    Permutation::identity(2).compose(&Permutation::identity(3));
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn permutation_apply_into() {
+    for n in [0, 1, 5, 1000] {
+        let p = shuffled(n, 3);
+        let source: Vec<String> = (0..n).map(|i| i.to_string()).collect();
+        let mut target = vec![String::new(); n];
+        p.apply_into(SliceParAccess::from_slice(&source), target.as_mut_slice())
+            .unwrap();
+        let expected: Vec<_> = (0..n).map(|i| source[p.get_index(i)].clone()).collect();
+        assert_eq!(target, expected);
+
+        // Gathering with the inverse restores the source
+        let mut restored = vec![String::new(); n];
+        p.inverse()
+            .apply_into(SliceParAccess::from_slice(&target), restored.as_mut_slice())
+            .unwrap();
+        assert_eq!(restored, source);
+    }
+
+    let p = Permutation::identity(3);
+    let err = p
+        .apply_into(
+            SliceParAccess::from_slice(&[0; 3]),
+            vec![0; 4].as_mut_slice(),
+        )
+        .unwrap_err();
+    assert_eq!((err.first, err.second), (3, 4));
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn permutation_apply_in_place() {
+    for n in [0, 1, 2, 7, 1000] {
+        for seed in [4, 5] {
+            let p = shuffled(n, seed);
+            let source: Vec<String> = (0..n).map(|i| i.to_string()).collect();
+            let mut data = source.clone();
+            p.apply_in_place(data.as_mut_slice()).unwrap();
+            let expected: Vec<_> = (0..n).map(|i| source[p.get_index(i)].clone()).collect();
+            assert_eq!(data, expected);
+        }
+    }
+
+    // Gathering with a composition is gathering with each permutation in turn
+    let (p, q) = (shuffled(50, 6), shuffled(50, 7));
+    let mut composed: Vec<usize> = (0..50).collect();
+    p.compose(&q)
+        .apply_in_place(composed.as_mut_slice())
+        .unwrap();
+    let mut sequential: Vec<usize> = (0..50).collect();
+    p.apply_in_place(sequential.as_mut_slice()).unwrap();
+    q.apply_in_place(sequential.as_mut_slice()).unwrap();
+    assert_eq!(composed, sequential);
+
+    let err = Permutation::identity(2)
+        .apply_in_place(vec![0; 3].as_mut_slice())
+        .unwrap_err();
+    assert_eq!((err.first, err.second), (2, 3));
+}
@@ -0,0 +1,51 @@
+use crate::unit_tests::assert_unique;
+use paradis::index::combinators::IndexProductN;
+use paradis::index::{collect_indices, index_product_n, ColumnMajor, IndexList, RowMajor};
+use paradis::Bounds;
+
+#[test]
+fn index_product_n_matches_nested_products() {
+    let product = index_product_n((0..3, 1..3, vec![7usize, 4]), RowMajor);
+    assert_unique(&index_product_n((0..3, 1..3, 2..4), RowMajor));
+    assert_eq!(product.num_indices(), 12);
+    assert_eq!(product.bounds(), None);
+
+    let indices: Vec<_> = collect_indices(&product);
+    let expected: Vec<_> = collect_indices(
+        (0..3)
+            .index_product(1..3)
+            .index_product(vec![7usize, 4])
+            .index_flatten(),
+    );
+    assert_eq!(indices, expected);
+}
+
+#[test]
+fn index_product_n_column_major() {
+    let product = index_product_n((0..2, 1..4), ColumnMajor);
+    let indices: Vec<_> = collect_indices(&product);
+    assert_eq!(
+        indices,
+        vec![(0, 1), (1, 1), (0, 2), (1, 2), (0, 3), (1, 3)]
+    );
+    assert_eq!(
+        product.bounds(),
+        Some(Bounds {
+            offset: (0, 1),
+            extent: (2, 3)
+        })
+    );
+
+    let product = index_product_n((0..2, 0..2, 0..2, 0..2, 0..2), ColumnMajor);
+    assert_eq!(product.num_indices(), 32);
+    assert_eq!(product.get_index(1), (1, 0, 0, 0, 0));
+    assert_eq!(product.get_index(16), (0, 0, 0, 0, 1));
+    assert_eq!(product.get_index(31), (1, 1, 1, 1, 1));
+}
+
+#[test]
+fn index_product_n_empty_and_overflow() {
+    let product = index_product_n((0..2, 0..0, 0..3), RowMajor);
+    assert_eq!(product.num_indices(), 0);
+    assert!(IndexProductN::try_new((0..usize::MAX, 0..2), RowMajor).is_err());
+}
@@ -0,0 +1,191 @@
+use crate::unit_tests::assert_unique;
+use paradis::index::patterns::MultiRange;
+use paradis::index::{collect_indices, ColumnMajor, IndexList, IndexRange, RowMajor};
+use paradis::Bounds;
+
+#[test]
+fn step_by_ranges() {
+    let even = (0..9).step_by(2);
+    assert_unique(&even);
+    assert_eq!(even.num_indices(), 5);
+    assert_eq!(
+        collect_indices::<Vec<_>, _>(even.clone()),
+        vec![0, 2, 4, 6, 8]
+    );
+    assert_eq!(
+        even.bounds(),
+        Some(Bounds {
+            offset: 0,
+            extent: 9
+        })
+    );
+
+    let odd = (1..9).step_by(2);
+    assert_eq!(collect_indices::<Vec<_>, _>(odd.clone()), vec![1, 3, 5, 7]);
+    assert_eq!(
+        odd.bounds(),
+        Some(Bounds {
+            offset: 1,
+            extent: 7
+        })
+    );
+
+    let stepped = (2..=11).step_by(3);
+    assert_unique(&stepped);
+    assert_eq!(
+        collect_indices::<Vec<_>, _>(stepped.clone()),
+        vec![2, 5, 8, 11]
+    );
+    assert_eq!(
+        stepped.bounds(),
+        Some(Bounds {
+            offset: 2,
+            extent: 10
+        })
+    );
+
+    let empty = (4..4).step_by(3);
+    assert_eq!(empty.num_indices(), 0);
+    assert_eq!(
+        empty.bounds(),
+        Some(Bounds {
+            offset: 0,
+            extent: 0
+        })
+    );
+}
+
+#[test]
+fn step_by_ranges_to_max() {
+    // The length of the underlying range does not fit in usize
+    let stepped = (0..=usize::MAX).step_by(usize::MAX / 2 + 1);
+    assert_eq!(stepped.num_indices(), 2);
+    assert_eq!(stepped.get_index(1), usize::MAX / 2 + 1);
+    assert_eq!(
+        stepped.bounds(),
+        Some(Bounds {
+            offset: 0,
+            extent: usize::MAX / 2 + 2
+        })
+    );
+
+    let stepped = (0..=usize::MAX).step_by(usize::MAX);
+    assert_eq!(stepped.num_indices(), 2);
+    assert_eq!(collect_indices::<Vec<_>, _>(&stepped), vec![0, usize::MAX]);
+
+    let stepped = (1..=usize::MAX).step_by(usize::MAX);
+    assert_eq!(stepped.num_indices(), 1);
+}
+
+#[test]
+#[should_panic(expected = "extent of bounds must fit in usize")]
+fn step_by_range_to_max_bounds_overflow() {
+    (0..=usize::MAX).step_by(usize::MAX).bounds();
+}
+
+#[test]
+#[should_panic(expected = "number of indices must fit in usize")]
+fn step_by_range_to_max_too_many_indices() {
+    (0..=usize::MAX).step_by(1).num_indices();
+}
+
+#[test]
+fn range_tuples() {
+    let ranges = (0..2, 1..4);
+    assert_unique(&ranges);
+    assert_eq!(ranges.num_indices(), 6);
+    assert_eq!(
+        collect_indices::<Vec<_>, _>(ranges.clone()),
+        collect_indices::<Vec<_>, _>((0..2).index_product(1..4))
+    );
+    assert_eq!(
+        ranges.bounds(),
+        Some(Bounds {
+            offset: (0, 1),
+            extent: (2, 3)
+        })
+    );
+
+    let ranges = (0..2, 0..0, 0..3);
+    assert_eq!(ranges.num_indices(), 0);
+
+    let ranges = (1..3, 0..2, 5..7);
+    let expected: Vec<_> = collect_indices(
+        (1..3)
+            .index_product(0..2)
+            .index_product(5..7)
+            .index_flatten(),
+    );
+    assert_eq!(collect_indices::<Vec<_>, _>(ranges), expected);
+}
+
+#[test]
+fn bounds_and_multi_range() {
+    let bounds = Bounds {
+        offset: (1, 2, 3),
+        extent: (2, 1, 2),
+    };
+    assert_unique(&bounds);
+    assert_eq!(bounds.num_indices(), 4);
+    assert_eq!(
+        collect_indices::<Vec<_>, _>(bounds),
+        vec![(1, 2, 3), (1, 2, 4), (2, 2, 3), (2, 2, 4)]
+    );
+    assert_eq!(IndexList::bounds(&bounds), Some(bounds));
+
+    let column_major = MultiRange::new(bounds, ColumnMajor);
+    assert_unique(&column_major);
+    assert_eq!(
+        collect_indices::<Vec<_>, _>(column_major),
+        vec![(1, 2, 3), (2, 2, 3), (1, 2, 4), (2, 2, 4)]
+    );
+
+    let row_major = MultiRange::new(bounds, RowMajor);
+    assert_eq!(
+        collect_indices::<Vec<_>, _>(row_major),
+        collect_indices::<Vec<_>, _>(bounds)
+    );
+
+    let bounds_1d = Bounds {
+        offset: 3,
+        extent: 2,
+    };
+    assert_eq!(collect_indices::<Vec<_>, _>(bounds_1d), vec![3, 4]);
+}
+
+#[test]
+fn integer_type_ranges() {
+    let indices = IndexRange::from(3u32..7);
+    assert_unique(&indices);
+    assert_eq!(collect_indices::<Vec<_>, _>(indices), vec![3u32, 4, 5, 6]);
+    assert_eq!(
+        indices.bounds(),
+        Some(Bounds {
+            offset: 3,
+            extent: 4
+        })
+    );
+
+    let indices = IndexRange::from(3u64..=7);
+    assert_unique(&indices);
+    assert_eq!(
+        collect_indices::<Vec<_>, _>(indices),
+        vec![3u64, 4, 5, 6, 7]
+    );
+
+    let cast: Vec<usize> = collect_indices(IndexRange::from(1u32..3).index_cast());
+    assert_eq!(cast, vec![1, 2]);
+
+    // Ranges that end before they start are empty
+    let (start, end) = (5u32, 2);
+    assert_eq!(IndexRange::from(start..end).num_indices(), 0);
+    let (start, end) = (5u64, 2);
+    assert_eq!(IndexRange::from(start..=end).num_indices(), 0);
+    assert_eq!(IndexRange::from(start..=end).bounds().unwrap().extent, 0);
+
+    // The largest index is allowed, as long as the end of the range can be represented
+    assert_eq!(
+        IndexRange::from(u32::MAX - 1..=u32::MAX - 1).num_indices(),
+        1
+    );
+}
@@ -0,0 +1,57 @@
+use crate::unit_tests::assert_unique;
+use paradis::index::{collect_indices, IndexList};
+use paradis::Bounds;
+
+#[test]
+fn subrange_skip_take() {
+    let indices = vec![3usize, 1, 4, 5, 9].check_unique().unwrap();
+
+    let middle = (&indices).index_subrange(1..4);
+    assert_unique(&middle);
+    assert_eq!(collect_indices::<Vec<_>, _>(&middle), vec![1, 4, 5]);
+    assert_eq!(middle.bounds(), indices.bounds());
+
+    assert_eq!(
+        collect_indices::<Vec<_>, _>((&indices).index_subrange(..=1)),
+        vec![3, 1]
+    );
+    assert_eq!(
+        collect_indices::<Vec<_>, _>((&indices).index_skip(3)),
+        vec![5, 9]
+    );
+    assert_eq!(
+        collect_indices::<Vec<_>, _>((&indices).index_take(2)),
+        vec![3, 1]
+    );
+    assert_eq!((&indices).index_skip(10).num_indices(), 0);
+    assert_eq!((&indices).index_take(10).num_indices(), 5);
+}
+
+#[test]
+#[should_panic]
+fn subrange_out_of_bounds() {
+    let _ = (0..3).index_subrange(1..4);
+}
+
+#[test]
+fn rev_and_step_by() {
+    let reversed = (2..6).index_rev();
+    assert_unique(&reversed);
+    assert_eq!(collect_indices::<Vec<_>, _>(&reversed), vec![5, 4, 3, 2]);
+    assert_eq!(
+        reversed.bounds(),
+        Some(Bounds {
+            offset: 2,
+            extent: 4
+        })
+    );
+
+    let stepped = (0..7).index_step_by(3);
+    assert_unique(&stepped);
+    assert_eq!(collect_indices::<Vec<_>, _>(&stepped), vec![0, 3, 6]);
+    assert_eq!((0..6).index_step_by(3).num_indices(), 2);
+    assert_eq!((0..0).index_step_by(3).num_indices(), 0);
+
+    let combined = (0..10).index_rev().index_step_by(4).index_take(2);
+    assert_eq!(collect_indices::<Vec<_>, _>(combined), vec![9, 5]);
+}
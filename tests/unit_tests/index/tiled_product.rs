@@ -0,0 +1,39 @@
+use crate::unit_tests::assert_unique;
+use paradis::index::{collect_indices, IndexList};
+
+#[test]
+fn tiled_product_visits_product_by_tiles() {
+    for (m, n) in [(0, 3), (1, 1), (5, 7), (8, 4), (13, 1)] {
+        for tile_shape in [(1, 1), (2, 3), (4, 4), (3, 1), (20, 20)] {
+            let tiled = (2..2 + m).index_tiled_product(10..10 + n, tile_shape);
+            let product = (2..2 + m).index_product(10..10 + n);
+            assert_unique(&tiled);
+            assert_eq!(tiled.num_indices(), product.num_indices());
+            assert_eq!(tiled.bounds(), product.bounds());
+
+            let indices: Vec<_> = collect_indices(&tiled);
+            let mut sorted = indices.clone();
+            sorted.sort();
+            assert_eq!(sorted, collect_indices::<Vec<_>, _>(&product));
+
+            // Each tile is visited contiguously
+            let (tm, tn) = tile_shape;
+            let tiles: Vec<_> = indices
+                .iter()
+                .map(|&(i, j)| ((i - 2) / tm, (j - 10) / tn))
+                .collect();
+            let mut visited = tiles.clone();
+            visited.dedup();
+            let mut unique_tiles = visited.clone();
+            unique_tiles.sort();
+            unique_tiles.dedup();
+            assert_eq!(visited.len(), unique_tiles.len());
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn tiled_product_rejects_empty_tiles() {
+    let _ = (0..3).index_tiled_product(0..3, (0, 2));
+}
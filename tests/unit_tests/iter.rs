@@ -11,3 +11,20 @@ fn basic_iteration() {
 
     assert_eq!(data, vec![0, 2, 4, 6, 8, 10]);
 }
+
+#[test]
+fn empty_iteration() {
+    let mut data: Vec<u32> = Vec::new();
+    assert_eq!(create_iter(data.as_mut_slice()).count(), 0);
+}
+
+#[test]
+fn iteration_len_decreases() {
+    let mut data = vec![0, 1, 2, 3];
+    let mut iter = create_iter(data.as_mut_slice());
+    assert_eq!(iter.len(), 4);
+    iter.next();
+    iter.next_back();
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.size_hint(), (2, Some(2)));
+}
@@ -1,3 +1,15 @@
+mod error;
+mod index;
 mod iter;
+#[cfg(feature = "rayon")]
+mod ops;
+mod patterns;
+#[cfg(feature = "rayon")]
+mod rayon;
 mod slice;
 mod unique;
+
+use paradis::index::UniqueIndexList;
+
+/// Statically asserts that an index list is unique.
+fn assert_unique<I: UniqueIndexList>(_: &I) {}
@@ -0,0 +1,241 @@
+use paradis::index::patterns::Repeat;
+use paradis::index::{narrow_access, narrow_shared_access, IndexFn, IndexList, UniqueIndexList};
+use paradis::ops::{gather, gather_into, scatter};
+use paradis::slice::SliceParAccess;
+use paradis::{Bounds, Error};
+use paradis_demo::DMatrixParAccessMut;
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+#[test]
+fn scatter_into_slice() {
+    let n = 1000;
+    let mut data = vec![0; n];
+    let indices: Vec<usize> = (0..n).map(|k| (7 * k) % n).collect();
+    let indices = indices.check_unique().unwrap();
+    let values: Vec<usize> = (0..n).collect();
+    scatter(values, &indices, data.as_mut_slice()).unwrap();
+    for k in 0..n {
+        assert_eq!(data[(7 * k) % n], k);
+    }
+}
+
+#[test]
+fn scatter_contiguous_range() {
+    let mut data = vec![0; 10];
+    let values = (0..4).into_par_iter().map(|k| k + 1);
+    scatter(values, &(3..7), data.as_mut_slice()).unwrap();
+    assert_eq!(data, vec![0, 0, 0, 1, 2, 3, 4, 0, 0, 0]);
+
+    scatter(Vec::new(), &(10..10), data.as_mut_slice()).unwrap();
+    assert_eq!(data, vec![0, 0, 0, 1, 2, 3, 4, 0, 0, 0]);
+}
+
+/// A range whose indices must only be computed from its contiguous bounds.
+struct ContiguousOnly(std::ops::Range<usize>);
+
+unsafe impl IndexList for ContiguousOnly {
+    type Index = usize;
+    const ALWAYS_BOUNDED: bool = true;
+
+    unsafe fn get_index_unchecked(&self, _loc: usize) -> usize {
+        panic!("indices of a contiguous list must not be looked up")
+    }
+
+    fn num_indices(&self) -> usize {
+        self.0.num_indices()
+    }
+
+    fn bounds(&self) -> Option<Bounds<usize>> {
+        self.0.bounds()
+    }
+
+    fn contiguous_bounds(&self) -> Option<Bounds<usize>> {
+        self.0.contiguous_bounds()
+    }
+}
+
+unsafe impl UniqueIndexList for ContiguousOnly {}
+
+#[test]
+fn scatter_contiguous_computes_indices() {
+    let mut data = vec![0; 1000];
+    let values: Vec<usize> = (1..=500).collect();
+    scatter(values, &ContiguousOnly(250..750), data.as_mut_slice()).unwrap();
+    assert!(data[..250].iter().all(|&x| x == 0));
+    assert!(data[250..750].iter().copied().eq(1..=500));
+    assert!(data[750..].iter().all(|&x| x == 0));
+
+    // Unit-step lists and contiguous sorted lists take the same path
+    let mut data = vec![0; 6];
+    scatter(vec![1, 2, 3], &(2..5).step_by(1), data.as_mut_slice()).unwrap();
+    assert_eq!(data, vec![0, 0, 1, 2, 3, 0]);
+    let indices = vec![1, 2, 3].check_unique_sorted().unwrap();
+    assert!(indices.contiguous_bounds().is_some());
+    scatter(vec![4, 5, 6], &indices, data.as_mut_slice()).unwrap();
+    assert_eq!(data, vec![0, 4, 5, 6, 3, 0]);
+
+    // Lists with gaps are looked up index by index
+    let indices = (0..6).step_by(2);
+    assert_eq!(indices.contiguous_bounds(), None);
+    scatter(vec![7, 8, 9], &indices, data.as_mut_slice()).unwrap();
+    assert_eq!(data, vec![7, 4, 8, 6, 9, 0]);
+
+    // Out of bounds contiguous lists are rejected before any record is written
+    let result = scatter(vec![1, 2], &(5..7), data.as_mut_slice());
+    assert!(matches!(result, Err(Error::OutOfBounds(_))));
+    assert_eq!(data, vec![7, 4, 8, 6, 9, 0]);
+}
+
+#[test]
+fn scatter_into_matrix() {
+    let mut matrix = nalgebra::DMatrix::<u32>::zeros(3, 4);
+    let access = DMatrixParAccessMut::from_matrix_mut(&mut matrix);
+    scatter(vec![1, 2, 3, 4, 5, 6], &(1..3, 1..4), access).unwrap();
+    assert_eq!(
+        matrix,
+        nalgebra::dmatrix![0, 0, 0, 0;
+                           0, 1, 2, 3;
+                           0, 4, 5, 6]
+    );
+}
+
+#[test]
+fn scatter_length_mismatch() {
+    let mut data = vec![0; 5];
+    let result = scatter(vec![1, 2, 3], &(0..2), data.as_mut_slice());
+    assert!(matches!(result, Err(Error::LengthMismatch(_))));
+    assert_eq!(data, vec![0; 5]);
+}
+
+#[test]
+fn scatter_out_of_bounds() {
+    let mut data = vec![0; 5];
+    let result = scatter(vec![1, 2, 3], &(3..6), data.as_mut_slice());
+    assert!(matches!(result, Err(Error::OutOfBounds(_))));
+    assert_eq!(data, vec![0; 5]);
+}
+
+#[test]
+fn scatter_unbounded_out_of_bounds() {
+    // Without bounds, every index is checked before any record is written
+    let indices = unsafe { IndexFn::new(4, |loc| 2 * loc).assume_injective() };
+    assert_eq!(indices.bounds(), None);
+
+    let mut data = vec![0; 7];
+    scatter(vec![1, 2, 3, 4], &indices, data.as_mut_slice()).unwrap();
+    assert_eq!(data, vec![1, 0, 2, 0, 3, 0, 4]);
+
+    let mut data = vec![0; 6];
+    let Err(Error::OutOfBounds(err)) = scatter(vec![1, 2, 3, 4], &indices, data.as_mut_slice())
+    else {
+        panic!("expected an out of bounds error");
+    };
+    assert_eq!(err.offending_index, Some((3, 6)));
+    assert_eq!(data, vec![0; 6]);
+}
+
+#[test]
+fn gather_reverses_scatter() {
+    let n = 1000;
+    let indices: Vec<usize> = (0..n).map(|k| (7 * k) % n).collect();
+    let indices = indices.check_unique().unwrap();
+    let values: Vec<String> = (0..n).map(|k| k.to_string()).collect();
+    let mut data = vec![String::new(); n];
+    scatter(values.clone(), &indices, data.as_mut_slice()).unwrap();
+
+    let access = narrow_access(data.as_mut_slice(), &indices).unwrap();
+    assert_eq!(gather(access), values);
+    let mut target = vec![String::new(); n];
+    let access = narrow_access(data.as_mut_slice(), &indices).unwrap();
+    gather_into(access, &mut target).unwrap();
+    assert_eq!(target, values);
+}
+
+#[test]
+fn gather_from_matrix() {
+    let mut matrix = nalgebra::dmatrix![0, 1, 2, 3;
+                                        4, 5, 6, 7;
+                                        8, 9, 10, 11];
+    let indices = (1..3, 1..4);
+    let access = DMatrixParAccessMut::from_matrix_mut(&mut matrix);
+    let gathered = gather(narrow_access(access, &indices).unwrap());
+    assert_eq!(gathered, vec![5, 6, 7, 9, 10, 11]);
+
+    let mut target = vec![0; 3];
+    let indices = (0..3).index_zip(0..3);
+    let access = DMatrixParAccessMut::from_matrix_mut(&mut matrix);
+    gather_into(narrow_access(access, &indices).unwrap(), &mut target).unwrap();
+    assert_eq!(target, vec![0, 5, 10]);
+}
+
+#[test]
+fn gather_whole_collection() {
+    let mut data = vec![1, 2, 3];
+    assert_eq!(gather(data.as_mut_slice()), vec![1, 2, 3]);
+    let mut target = vec![0; 3];
+    gather_into(SliceParAccess::from_slice(&data), &mut target).unwrap();
+    assert_eq!(target, data);
+}
+
+#[test]
+fn gather_into_length_mismatch_leaves_target_unchanged() {
+    let mut data = vec![1, 2, 3, 4, 5];
+    let mut target = vec![0; 3];
+    let access = narrow_access(data.as_mut_slice(), &(0..2)).unwrap();
+    let err = gather_into(access, &mut target).unwrap_err();
+    assert_eq!((err.first, err.second), (2, 3));
+    assert_eq!(target, vec![0; 3]);
+}
+
+#[test]
+fn gather_shared_repeated_indices() {
+    let data: Vec<String> = ["a", "b", "c"].map(String::from).to_vec();
+    let indices = vec![2, 0, 2, 2, 1, 0];
+    let expected: Vec<String> = ["c", "a", "c", "c", "b", "a"].map(String::from).to_vec();
+
+    let access = narrow_shared_access(SliceParAccess::from_slice(&data), &indices).unwrap();
+    assert_eq!(gather(access), expected);
+
+    let mut target = vec![String::new(); 6];
+    let access = narrow_shared_access(SliceParAccess::from_slice(&data), &indices).unwrap();
+    gather_into(access, &mut target).unwrap();
+    assert_eq!(target, expected);
+}
+
+#[test]
+fn gather_shared_many_repeats() {
+    let data: Vec<u64> = (0..10).map(|i| i * i).collect();
+    let indices: Vec<usize> = (0..10_000).map(|k| k % 10).collect();
+    let expected: Vec<u64> = indices.iter().map(|&i| data[i]).collect();
+
+    let access = narrow_shared_access(SliceParAccess::from_slice(&data), &indices).unwrap();
+    assert_eq!(gather(access), expected);
+
+    // Bounded lists with repeated indices are checked through their bounds
+    let indices = Repeat::value(7).times(1000);
+    let access = narrow_shared_access(SliceParAccess::from_slice(&data), &indices).unwrap();
+    assert_eq!(gather(access), vec![49; 1000]);
+    let indices = Repeat::value(10).times(1000);
+    assert!(narrow_shared_access(SliceParAccess::from_slice(&data), &indices).is_err());
+}
+
+#[test]
+fn gather_shared_out_of_bounds() {
+    // Vec has no bounds, so every index is checked before any record is read
+    let data = vec![1, 2, 3];
+    let access = SliceParAccess::from_slice(&data);
+    let Err(err) = narrow_shared_access(access, &vec![0, 2, 3, 1]) else {
+        panic!("expected an out of bounds error");
+    };
+    assert_eq!(err.offending_index, Some((2, 3)));
+    assert_eq!(
+        err.index_bounds,
+        Bounds {
+            offset: 0,
+            extent: 4
+        }
+    );
+
+    let access = SliceParAccess::from_slice(&data);
+    assert!(narrow_shared_access(access, &(1..4)).is_err());
+}
@@ -0,0 +1,94 @@
+use crate::unit_tests::assert_unique;
+use paradis::index::patterns::{Band, Diagonal};
+use paradis::index::{collect_indices, IndexList};
+use paradis::Bounds;
+
+fn brute_force(shape: (usize, usize), keep: impl Fn(isize) -> bool) -> Vec<(usize, usize)> {
+    let all: Vec<(usize, usize)> = collect_indices((0..shape.0, 0..shape.1));
+    all.into_iter()
+        .filter(|&(i, j)| keep(j as isize - i as isize))
+        .collect()
+}
+
+fn tight_bounds(indices: &[(usize, usize)]) -> Bounds<(usize, usize)> {
+    indices
+        .iter()
+        .fold(Bounds::new_empty(), |mut bounds, &index| {
+            bounds.enclose_index(index);
+            bounds
+        })
+}
+
+const SHAPES: [(usize, usize); 9] = [
+    (0, 0),
+    (0, 4),
+    (4, 0),
+    (1, 1),
+    (5, 5),
+    (3, 8),
+    (8, 3),
+    (1, 6),
+    (6, 1),
+];
+
+#[test]
+fn diagonal_matches_brute_force() {
+    for shape in SHAPES {
+        for k in -9..=9 {
+            let diagonal = Diagonal::new(shape, k);
+            let expected = brute_force(shape, |d| d == k);
+            assert_unique(&diagonal);
+            assert_eq!(diagonal.k(), k);
+            assert_eq!(collect_indices::<Vec<_>, _>(diagonal), expected);
+            assert_eq!(diagonal.bounds(), Some(tight_bounds(&expected)));
+        }
+    }
+}
+
+#[test]
+fn band_matches_brute_force() {
+    for shape in SHAPES {
+        for lower in 0..10 {
+            for upper in 0..10 {
+                let band = Band::new(shape, lower, upper);
+                let expected =
+                    brute_force(shape, |d| -(lower as isize) <= d && d <= upper as isize);
+                assert_unique(&band);
+                assert_eq!(band.num_indices(), expected.len());
+                assert_eq!(collect_indices::<Vec<_>, _>(band), expected);
+                assert_eq!(band.bounds(), Some(tight_bounds(&expected)));
+            }
+        }
+    }
+}
+
+#[test]
+fn band_large_shapes() {
+    // Pentadiagonal
+    let n = 1 << 30;
+    let band = Band::new((n, n), 2, 2);
+    assert_eq!(band.num_indices(), 5 * n - 6);
+    assert_eq!(band.get_index(0), (0, 0));
+    assert_eq!(band.get_index(3), (1, 0));
+    assert_eq!(band.get_index(5 * n - 7), (n - 1, n - 1));
+    assert_eq!(band.get_index(5 * n - 8), (n - 1, n - 2));
+
+    // Every row spans the full width
+    let n = usize::MAX / 4;
+    let band = Band::new((3, n), usize::MAX, usize::MAX);
+    assert_eq!(band.num_indices(), 3 * n);
+    assert_eq!(band.get_index(n), (1, 0));
+    assert_eq!(band.get_index(3 * n - 1), (2, n - 1));
+}
+
+#[test]
+fn band_max_extent() {
+    let band = Band::new((1, usize::MAX), 0, usize::MAX);
+    assert_eq!(band.num_indices(), usize::MAX);
+    assert_eq!(band.get_index(0), (0, 0));
+    assert_eq!(band.get_index(usize::MAX - 1), (0, usize::MAX - 1));
+
+    let band = Band::new((usize::MAX, 1), usize::MAX, 0);
+    assert_eq!(band.num_indices(), usize::MAX);
+    assert_eq!(band.get_index(usize::MAX - 1), (usize::MAX - 1, 0));
+}
@@ -0,0 +1,152 @@
+use crate::unit_tests::assert_unique;
+use paradis::index::patterns::{Boundary, Interior};
+use paradis::index::{collect_indices, IndexList};
+use paradis::Bounds;
+use std::collections::HashSet;
+
+#[test]
+fn boundary_and_interior_2d_match_filtered_bounds() {
+    for offset in [(0, 0), (3, 8)] {
+        for extent in [(0, 4), (1, 1), (2, 5), (3, 3), (4, 7), (9, 2), (1, 10)] {
+            let bounds = Bounds { offset, extent };
+            for width in 0..=5 {
+                let boundary = Boundary::new(bounds, width);
+                let interior = Interior::new(bounds, width);
+                assert_unique(&boundary);
+                assert_unique(&interior);
+                assert_eq!(boundary.width(), width);
+
+                let is_interior = |(i, j): (usize, usize)| {
+                    let (i, j) = (i - offset.0, j - offset.1);
+                    i >= width && i + width < extent.0 && j >= width && j + width < extent.1
+                };
+                let all: Vec<_> = collect_indices(bounds);
+                let expected_boundary: Vec<_> = all
+                    .iter()
+                    .copied()
+                    .filter(|&idx| !is_interior(idx))
+                    .collect();
+                let expected_interior: Vec<_> = all
+                    .iter()
+                    .copied()
+                    .filter(|&idx| is_interior(idx))
+                    .collect();
+                assert_eq!(boundary.num_indices(), expected_boundary.len());
+                assert_eq!(interior.num_indices(), expected_interior.len());
+                assert_eq!(collect_indices::<Vec<_>, _>(boundary), expected_boundary);
+                assert_eq!(collect_indices::<Vec<_>, _>(interior), expected_interior);
+            }
+        }
+    }
+}
+
+#[test]
+fn boundary_and_interior_3d_match_filtered_bounds() {
+    for offset in [(0, 0, 0), (1, 4, 2)] {
+        for extent in [
+            (2, 2, 2),
+            (3, 4, 5),
+            (1, 7, 2),
+            (5, 5, 5),
+            (6, 3, 8),
+            (2, 0, 3),
+        ] {
+            let bounds = Bounds { offset, extent };
+            for width in 0..=3 {
+                let is_interior = |(i, j, l): (usize, usize, usize)| {
+                    let (i, j, l) = (i - offset.0, j - offset.1, l - offset.2);
+                    [(i, extent.0), (j, extent.1), (l, extent.2)]
+                        .into_iter()
+                        .all(|(x, n)| x >= width && x + width < n)
+                };
+                let all: Vec<_> = collect_indices(bounds);
+                let expected_boundary: Vec<_> = all
+                    .iter()
+                    .copied()
+                    .filter(|&idx| !is_interior(idx))
+                    .collect();
+                let expected_interior: Vec<_> = all
+                    .iter()
+                    .copied()
+                    .filter(|&idx| is_interior(idx))
+                    .collect();
+                let boundary = Boundary::new(bounds, width);
+                let interior = Interior::new(bounds, width);
+                assert_eq!(collect_indices::<Vec<_>, _>(boundary), expected_boundary);
+                assert_eq!(collect_indices::<Vec<_>, _>(interior), expected_interior);
+            }
+        }
+    }
+}
+
+#[test]
+fn boundary_and_interior_cover_bounds_exactly() {
+    let bounds = Bounds {
+        offset: (2, 1, 5),
+        extent: (7, 4, 9),
+    };
+    for width in 0..=5 {
+        let boundary: Vec<_> = collect_indices(Boundary::new(bounds, width));
+        let interior: Vec<_> = collect_indices(Interior::new(bounds, width));
+        assert_eq!(boundary.len() + interior.len(), 7 * 4 * 9);
+
+        let mut seen = HashSet::new();
+        for idx in boundary.into_iter().chain(interior) {
+            assert!(
+                seen.insert(idx),
+                "index {idx:?} is in both the boundary and the interior"
+            );
+        }
+        let expected: HashSet<_> = collect_indices(bounds);
+        assert_eq!(seen, expected);
+    }
+}
+
+#[test]
+fn boundary_and_interior_bounds() {
+    let bounds = Bounds {
+        offset: (2, 3),
+        extent: (6, 5),
+    };
+    assert_eq!(Boundary::new(bounds, 1).bounds(), Some(bounds));
+    assert_eq!(
+        Interior::new(bounds, 2).bounds(),
+        Some(Bounds {
+            offset: (4, 5),
+            extent: (2, 1),
+        })
+    );
+    assert!(Boundary::new(bounds, 0).bounds().unwrap().is_empty());
+    assert!(Interior::new(bounds, 3).bounds().unwrap().is_empty());
+}
+
+#[test]
+fn boundary_large_bounds() {
+    let n = 1usize << 20;
+    let bounds = Bounds {
+        offset: (0, 0),
+        extent: (n, n),
+    };
+    let boundary = Boundary::new(bounds, 1);
+    assert_eq!(boundary.num_indices(), 4 * n - 4);
+    assert_eq!(boundary.get_index(n - 1), (0, n - 1));
+    assert_eq!(boundary.get_index(n), (1, 0));
+    assert_eq!(boundary.get_index(n + 1), (1, n - 1));
+    assert_eq!(boundary.get_index(4 * n - 5), (n - 1, n - 1));
+
+    let interior = Interior::new(bounds, 1);
+    assert_eq!(interior.num_indices(), (n - 2) * (n - 2));
+    assert_eq!(interior.get_index(n - 2), (2, 1));
+}
+
+#[test]
+fn boundary_and_interior_1d() {
+    let bounds = Bounds {
+        offset: 2,
+        extent: 7,
+    };
+    let boundary: Vec<_> = collect_indices(Boundary::new(bounds, 2));
+    let interior: Vec<_> = collect_indices(Interior::new(bounds, 2));
+    assert_eq!(boundary, vec![2, 3, 7, 8]);
+    assert_eq!(interior, vec![4, 5, 6]);
+}
@@ -0,0 +1,114 @@
+use crate::unit_tests::assert_unique;
+use paradis::index::patterns::Checkerboard;
+use paradis::index::{collect_indices, DisjointClasses, IndexList};
+use paradis::Bounds;
+use std::collections::HashSet;
+
+#[test]
+fn checkerboard_2d_matches_filtered_bounds() {
+    for offset in [(0, 0), (3, 8)] {
+        for extent in [(0, 4), (1, 1), (3, 3), (4, 7), (9, 2), (1, 10)] {
+            let bounds = Bounds { offset, extent };
+            for k in 1..=5usize {
+                for color in 0..k {
+                    let checkerboard = Checkerboard::new(bounds, k, color);
+                    assert_unique(&checkerboard);
+                    let expected: Vec<_> = collect_indices::<Vec<_>, _>(bounds)
+                        .into_iter()
+                        .filter(|&(i, j)| (i + j) % k == color)
+                        .collect();
+                    assert_eq!(checkerboard.num_indices(), expected.len());
+                    assert_eq!(collect_indices::<Vec<_>, _>(checkerboard), expected);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn checkerboard_3d_matches_filtered_bounds() {
+    for offset in [(0, 0, 0), (1, 4, 2)] {
+        for extent in [(2, 2, 2), (3, 4, 5), (1, 7, 2), (5, 1, 1), (2, 0, 3)] {
+            let bounds = Bounds { offset, extent };
+            for k in 1..=4usize {
+                for color in 0..k {
+                    let checkerboard = Checkerboard::new(bounds, k, color);
+                    let expected: Vec<_> = collect_indices::<Vec<_>, _>(bounds)
+                        .into_iter()
+                        .filter(|&(i, j, l)| (i + j + l) % k == color)
+                        .collect();
+                    assert_eq!(collect_indices::<Vec<_>, _>(checkerboard), expected);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn checkerboard_colors_cover_bounds_exactly() {
+    let bounds = Bounds {
+        offset: (2, 1, 5),
+        extent: (4, 3, 6),
+    };
+    for k in 1..=6 {
+        let colors = Checkerboard::colors(bounds, k);
+        assert_eq!(colors.len(), k);
+        let mut seen = HashSet::new();
+        for (color, checkerboard) in colors.iter().enumerate() {
+            assert_eq!(checkerboard.color(), color);
+            assert_eq!(checkerboard.num_colors(), k);
+            for idx in collect_indices::<Vec<_>, _>(checkerboard.clone()) {
+                assert!(seen.insert(idx), "index {idx:?} has more than one colour");
+            }
+        }
+        let expected: HashSet<_> = collect_indices(bounds);
+        assert_eq!(seen, expected);
+        assert!(DisjointClasses::from_classes(colors.into_inner()).is_ok());
+    }
+}
+
+#[test]
+fn checkerboard_bounds() {
+    let bounds = Bounds {
+        offset: (4, 4),
+        extent: (1, 1),
+    };
+    assert_eq!(Checkerboard::new(bounds, 2, 0).bounds(), Some(bounds));
+    let empty = Checkerboard::new(bounds, 2, 1);
+    assert_eq!(empty.num_indices(), 0);
+    assert!(empty.bounds().unwrap().is_empty());
+}
+
+#[test]
+fn checkerboard_large_bounds() {
+    let n = 1usize << 20;
+    let bounds = Bounds {
+        offset: (0, 0),
+        extent: (n, n),
+    };
+    let red = Checkerboard::new(bounds, 2, 0);
+    assert_eq!(red.num_indices(), n * n / 2);
+    assert_eq!(red.get_index(0), (0, 0));
+    assert_eq!(red.get_index(n / 2), (1, 1));
+    assert_eq!(red.get_index(n * n / 2 - 1), (n - 1, n - 1));
+}
+
+#[test]
+#[should_panic]
+fn checkerboard_color_out_of_range_panics() {
+    let bounds = Bounds {
+        offset: (0, 0),
+        extent: (2, 2),
+    };
+    Checkerboard::new(bounds, 2, 2);
+}
+
+#[test]
+fn checkerboard_1d() {
+    let bounds = Bounds {
+        offset: 2,
+        extent: 7,
+    };
+    let indices: Vec<_> = collect_indices(Checkerboard::new(bounds, 3, 1));
+    assert_eq!(indices, vec![4, 7]);
+}
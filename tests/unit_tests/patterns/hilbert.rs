@@ -0,0 +1,88 @@
+use crate::unit_tests::assert_unique;
+use paradis::index::patterns::{Hilbert2d, Hilbert3d};
+use paradis::index::{collect_indices, IndexList};
+use paradis::Bounds;
+
+fn distance(a: &[usize], b: &[usize]) -> usize {
+    a.iter().zip(b).map(|(&x, &y)| x.abs_diff(y)).sum()
+}
+
+#[test]
+fn hilbert_2d_is_continuous_on_squares() {
+    for n in [1, 2, 4, 8, 16, 32] {
+        let bounds = Bounds {
+            offset: (3, 1),
+            extent: (n, n),
+        };
+        let hilbert = Hilbert2d::new(bounds);
+        let indices: Vec<_> = collect_indices(hilbert);
+        for pair in indices.windows(2) {
+            let (a, b) = (pair[0], pair[1]);
+            assert_eq!(distance(&[a.0, a.1], &[b.0, b.1]), 1);
+        }
+        assert_eq!(indices.first(), Some(&(3, 1)));
+        let mut sorted = indices;
+        sorted.sort();
+        assert_eq!(sorted, collect_indices::<Vec<_>, _>(bounds));
+    }
+}
+
+#[test]
+fn hilbert_3d_is_continuous_on_cubes() {
+    for n in [1, 2, 4, 8, 16] {
+        let bounds = Bounds {
+            offset: (0, 2, 1),
+            extent: (n, n, n),
+        };
+        let hilbert = Hilbert3d::new(bounds);
+        let indices: Vec<_> = collect_indices(hilbert);
+        for pair in indices.windows(2) {
+            let (a, b) = (pair[0], pair[1]);
+            assert_eq!(distance(&[a.0, a.1, a.2], &[b.0, b.1, b.2]), 1);
+        }
+        let mut sorted = indices;
+        sorted.sort();
+        assert_eq!(sorted, collect_indices::<Vec<_>, _>(bounds));
+    }
+}
+
+#[test]
+fn hilbert_restricted_to_bounds() {
+    // The order on arbitrary bounds is that of the smallest enclosing cube, skipping other indices
+    for extent in [(3usize, 5usize), (16, 1), (9, 16), (0, 3), (1, 1)] {
+        let n = extent.0.max(extent.1).next_power_of_two();
+        let cube: Vec<_> = collect_indices(Hilbert2d::new(Bounds {
+            offset: (0, 0),
+            extent: (n, n),
+        }));
+        let hilbert = Hilbert2d::new(Bounds {
+            offset: (0, 0),
+            extent,
+        });
+        assert_unique(&hilbert);
+        assert_eq!(hilbert.num_indices(), extent.0 * extent.1);
+        let expected: Vec<_> = cube
+            .into_iter()
+            .filter(|&(i, j)| i < extent.0 && j < extent.1)
+            .collect();
+        assert_eq!(collect_indices::<Vec<_>, _>(hilbert), expected);
+    }
+
+    for extent in [(3usize, 7usize, 5usize), (2, 1, 9)] {
+        let n = extent.0.max(extent.1).max(extent.2).next_power_of_two();
+        let cube: Vec<_> = collect_indices(Hilbert3d::new(Bounds {
+            offset: (0, 0, 0),
+            extent: (n, n, n),
+        }));
+        let hilbert = Hilbert3d::new(Bounds {
+            offset: (0, 0, 0),
+            extent,
+        });
+        assert_unique(&hilbert);
+        let expected: Vec<_> = cube
+            .into_iter()
+            .filter(|&(i, j, k)| i < extent.0 && j < extent.1 && k < extent.2)
+            .collect();
+        assert_eq!(collect_indices::<Vec<_>, _>(hilbert), expected);
+    }
+}
@@ -0,0 +1,6 @@
+mod band;
+mod boundary;
+mod checkerboard;
+mod hilbert;
+mod morton;
+mod triangular;
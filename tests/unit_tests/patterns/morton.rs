@@ -0,0 +1,84 @@
+use crate::unit_tests::assert_unique;
+use paradis::index::patterns::Morton;
+use paradis::index::{collect_indices, narrow_access, IndexList};
+use paradis::iter::create_iter;
+use paradis::Bounds;
+use paradis_demo::DMatrixParAccessMut;
+
+/// Interleave the bits of the coordinates, with the first coordinate most significant.
+fn morton_code(coords: &[usize]) -> u128 {
+    let mut code = 0;
+    for bit in (0..usize::BITS).rev() {
+        for &c in coords {
+            code = (code << 1) | ((c >> bit) & 1) as u128;
+        }
+    }
+    code
+}
+
+#[test]
+fn morton_2d_matches_sorted_codes() {
+    for (m, n) in [(1, 1), (4, 4), (3, 5), (8, 2), (7, 13), (1, 9), (0, 4)] {
+        let bounds = Bounds {
+            offset: (2, 5),
+            extent: (m, n),
+        };
+        let morton = Morton::new(bounds);
+        assert_unique(&morton);
+        assert_eq!(morton.bounds(), Some(bounds));
+        assert_eq!(morton.num_indices(), m * n);
+
+        let indices: Vec<_> = collect_indices(morton);
+        let mut expected: Vec<_> = collect_indices(bounds);
+        expected.sort_by_key(|&(i, j)| morton_code(&[i - 2, j - 5]));
+        assert_eq!(indices, expected);
+    }
+}
+
+#[test]
+fn morton_3d_matches_sorted_codes() {
+    for extent in [(2, 2, 2), (3, 4, 5), (1, 7, 2), (5, 1, 1)] {
+        let bounds = Bounds {
+            offset: (1, 0, 3),
+            extent,
+        };
+        let morton = Morton::new(bounds);
+        assert_unique(&morton);
+        let indices: Vec<_> = collect_indices(morton);
+        let mut expected: Vec<_> = collect_indices(bounds);
+        expected.sort_by_key(|&(i, j, k)| morton_code(&[i - 1, j, k - 3]));
+        assert_eq!(indices, expected);
+    }
+}
+
+#[test]
+fn morton_narrow_access() {
+    let mut matrix = nalgebra::DMatrix::<usize>::zeros(3, 5);
+    let morton = Morton::new(Bounds {
+        offset: (0, 0),
+        extent: (3, 5),
+    });
+    let access = DMatrixParAccessMut::from_matrix_mut(&mut matrix);
+    let access = narrow_access(access, &morton).unwrap();
+    for (rank, entry) in create_iter(access).enumerate() {
+        *entry = rank;
+    }
+    assert_eq!(
+        matrix,
+        nalgebra::dmatrix![0, 1, 4, 5, 12;
+                           2, 3, 6, 7, 13;
+                           8, 9, 10, 11, 14]
+    );
+}
+
+#[test]
+fn morton_1d_is_natural_order() {
+    let bounds = Bounds {
+        offset: 3,
+        extent: 5,
+    };
+    let morton = Morton::new(bounds);
+    assert_unique(&morton);
+    assert_eq!(morton.bounds(), Some(bounds));
+    assert_eq!(collect_indices::<Vec<_>, _>(morton), vec![3, 4, 5, 6, 7]);
+}
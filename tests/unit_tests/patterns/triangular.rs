@@ -0,0 +1,107 @@
+use crate::unit_tests::assert_unique;
+use paradis::index::patterns::{LowerTriangular, UpperTriangular};
+use paradis::index::{collect_indices, IndexList};
+use paradis::Bounds;
+
+fn brute_force(shape: (usize, usize), keep: impl Fn(usize, usize) -> bool) -> Vec<(usize, usize)> {
+    let all: Vec<(usize, usize)> = collect_indices((0..shape.0, 0..shape.1));
+    all.into_iter().filter(|&(i, j)| keep(i, j)).collect()
+}
+
+fn tight_bounds(indices: &[(usize, usize)]) -> Bounds<(usize, usize)> {
+    indices
+        .iter()
+        .fold(Bounds::new_empty(), |mut bounds, &index| {
+            bounds.enclose_index(index);
+            bounds
+        })
+}
+
+const SHAPES: [(usize, usize); 10] = [
+    (0, 0),
+    (0, 3),
+    (3, 0),
+    (1, 1),
+    (4, 4),
+    (3, 7),
+    (7, 3),
+    (1, 5),
+    (5, 1),
+    (10, 10),
+];
+
+#[test]
+fn upper_triangular_matches_brute_force() {
+    for shape in SHAPES {
+        for (pattern, expected) in [
+            (
+                UpperTriangular::new(shape),
+                brute_force(shape, |i, j| i <= j),
+            ),
+            (
+                UpperTriangular::strict(shape),
+                brute_force(shape, |i, j| i < j),
+            ),
+        ] {
+            assert_unique(&pattern);
+            assert_eq!(collect_indices::<Vec<_>, _>(pattern), expected);
+            assert_eq!(pattern.bounds(), Some(tight_bounds(&expected)));
+        }
+    }
+}
+
+#[test]
+fn lower_triangular_matches_brute_force() {
+    for shape in SHAPES {
+        for (pattern, expected) in [
+            (
+                LowerTriangular::new(shape),
+                brute_force(shape, |i, j| i >= j),
+            ),
+            (
+                LowerTriangular::strict(shape),
+                brute_force(shape, |i, j| i > j),
+            ),
+        ] {
+            assert_unique(&pattern);
+            assert_eq!(collect_indices::<Vec<_>, _>(pattern), expected);
+            assert_eq!(pattern.bounds(), Some(tight_bounds(&expected)));
+        }
+    }
+}
+
+#[test]
+fn triangular_large_shapes() {
+    let upper = UpperTriangular::new((2, 1 << 62));
+    assert_eq!(upper.num_indices(), (1 << 63) - 1);
+    assert_eq!(upper.get_index(0), (0, 0));
+    assert_eq!(upper.get_index(1 << 62), (1, 1));
+    assert_eq!(upper.get_index((1 << 63) - 2), (1, (1 << 62) - 1));
+
+    let lower = LowerTriangular::new((1 << 40, 3));
+    assert_eq!(lower.num_indices(), 6 + ((1 << 40) - 3) * 3);
+    assert_eq!(lower.get_index(5), (2, 2));
+    assert_eq!(lower.get_index(lower.num_indices() - 1), ((1 << 40) - 1, 2));
+
+    let upper = UpperTriangular::strict((1 << 20, 1 << 20));
+    let n = (1usize << 20) * ((1 << 20) - 1) / 2;
+    assert_eq!(upper.num_indices(), n);
+    assert_eq!(upper.get_index(n - 1), ((1 << 20) - 2, (1 << 20) - 1));
+}
+
+#[test]
+fn triangular_max_extent() {
+    let upper = UpperTriangular::new((1, usize::MAX));
+    assert_eq!(upper.num_indices(), usize::MAX);
+    assert_eq!(upper.get_index(0), (0, 0));
+    assert_eq!(upper.get_index(usize::MAX - 1), (0, usize::MAX - 1));
+
+    let upper = UpperTriangular::strict((1, usize::MAX));
+    assert_eq!(upper.num_indices(), usize::MAX - 1);
+    assert_eq!(upper.get_index(0), (0, 1));
+    assert_eq!(upper.get_index(usize::MAX - 2), (0, usize::MAX - 1));
+
+    let lower = LowerTriangular::new((usize::MAX, 1));
+    assert_eq!(lower.num_indices(), usize::MAX);
+    assert_eq!(lower.get_index(usize::MAX - 1), (usize::MAX - 1, 0));
+}
@@ -0,0 +1,140 @@
+use crate::unit_tests::assert_unique;
+use paradis::index::coloring::Coloring;
+use paradis::index::patterns::{Boundary, Checkerboard, Interior};
+use paradis::index::{DisjointClasses, IndexFn, IndexList};
+use paradis::rayon::{create_par_iter, for_each_colored};
+use paradis::Bounds;
+use paradis_demo::DMatrixParAccessMut;
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[test]
+fn par_iter_split_at_end() {
+    // Adaptors such as `skip` and `take` split the producer at its full length
+    let mut data = vec![1, 2, 3];
+    assert_eq!(create_par_iter(data.as_mut_slice()).skip(3).count(), 0);
+    assert_eq!(create_par_iter(data.as_mut_slice()).take(3).count(), 3);
+
+    let mut empty: Vec<u32> = Vec::new();
+    assert_eq!(create_par_iter(empty.as_mut_slice()).count(), 0);
+}
+
+#[test]
+fn for_each_colored_red_black_sweep() {
+    // Each entry records how many entries were visited before it, so all red entries must
+    // have smaller values than all black entries
+    let (m, n) = (5, 7);
+    let mut matrix = nalgebra::DMatrix::<usize>::zeros(m, n);
+    let bounds = Bounds {
+        offset: (0, 0),
+        extent: (m, n),
+    };
+    let colors = Checkerboard::colors(bounds, 2);
+    let num_visited = AtomicUsize::new(0);
+    let access = DMatrixParAccessMut::from_matrix_mut(&mut matrix);
+    for_each_colored(access, &colors, |entry| {
+        *entry = num_visited.fetch_add(1, Ordering::Relaxed);
+    })
+    .unwrap();
+
+    let num_red = colors[0].num_indices();
+    assert_eq!(num_visited.into_inner(), m * n);
+    for i in 0..m {
+        for j in 0..n {
+            assert_eq!(matrix[(i, j)] < num_red, (i + j) % 2 == 0);
+        }
+    }
+}
+
+#[test]
+fn for_each_colored_visits_every_index_once_per_class() {
+    let items: Vec<Vec<usize>> = (0..200).map(|i| vec![i % 17, (i * 7) % 23 + 17]).collect();
+    let coloring = Coloring::greedy(items.iter().map(|item| item.iter().copied()));
+    let mut counts = vec![0u32; items.len()];
+    for_each_colored(counts.as_mut_slice(), coloring.classes(), |count| {
+        *count += 1
+    })
+    .unwrap();
+    assert!(counts.iter().all(|&count| count == 1));
+}
+
+#[test]
+fn for_each_colored_with_empty_classes() {
+    let mut matrix = nalgebra::DMatrix::<u32>::zeros(1, 1);
+    let bounds = Bounds {
+        offset: (0, 0),
+        extent: (1, 1),
+    };
+    let colors = Checkerboard::colors(bounds, 3);
+    assert_eq!(colors[1].num_indices(), 0);
+    let access = DMatrixParAccessMut::from_matrix_mut(&mut matrix);
+    for_each_colored(access, &colors, |entry| *entry += 1).unwrap();
+    assert_eq!(matrix[(0, 0)], 1);
+}
+
+#[test]
+fn for_each_colored_checks_bounds_up_front() {
+    let mut matrix = nalgebra::DMatrix::<u32>::zeros(4, 4);
+    let inside = Bounds {
+        offset: (0, 0),
+        extent: (4, 4),
+    };
+    let outside = Bounds {
+        offset: (3, 3),
+        extent: (2, 2),
+    };
+    let colors =
+        DisjointClasses::from_classes(vec![Interior::new(inside, 1), Interior::new(outside, 0)])
+            .unwrap();
+    assert_unique(&colors[0]);
+    let access = DMatrixParAccessMut::from_matrix_mut(&mut matrix);
+    let err = for_each_colored(access, &colors, |entry| *entry += 1).unwrap_err();
+    assert_eq!(err.index_bounds, colors[1].bounds().unwrap());
+    // No colour is processed if any colour is out of bounds
+    assert!(matrix.iter().all(|&entry| entry == 0));
+
+    let center = Bounds {
+        offset: (1, 1),
+        extent: (2, 2),
+    };
+    let colors =
+        DisjointClasses::from_classes(vec![Boundary::new(inside, 1), Boundary::new(center, 1)])
+            .unwrap();
+    let access = DMatrixParAccessMut::from_matrix_mut(&mut matrix);
+    for_each_colored(access, &colors, |entry| *entry += 1).unwrap();
+    assert!(matrix.iter().all(|&entry| entry == 1));
+}
+
+#[test]
+fn for_each_colored_with_checked_classes() {
+    let bounds = Bounds {
+        offset: (0, 0),
+        extent: (4, 4),
+    };
+    // The outer two layers of the boundary contain the outermost layer
+    let colors = vec![Boundary::new(bounds, 1), Boundary::new(bounds, 2)];
+    let err = DisjointClasses::par_from_classes(colors.clone()).unwrap_err();
+    let concatenated: Vec<_> = colors
+        .iter()
+        .flat_map(|indices| (0..indices.num_indices()).map(|loc| indices.get_index(loc)))
+        .collect();
+    assert_eq!(concatenated[err.first], err.index);
+    assert_eq!(concatenated[err.second], err.index);
+    assert!(err.first < colors[0].num_indices());
+    assert!(err.second >= colors[0].num_indices());
+
+    // Classes without bounds can be checked as well
+    let mut data = vec![0u32; 6];
+    // SAFETY: The functions are deterministic and injective
+    let every_other =
+        |start: usize| unsafe { IndexFn::new(3, move |loc| 2 * loc + start).assume_injective() };
+    let (even, odd) = (every_other(0), every_other(1));
+    assert_eq!(odd.bounds(), None);
+    assert!(DisjointClasses::par_from_classes(vec![odd.clone(), odd.clone()]).is_err());
+    // The same checked classes can be used for any number of sweeps
+    let colors = DisjointClasses::par_from_classes(vec![odd, even]).unwrap();
+    for _ in 0..3 {
+        for_each_colored(data.as_mut_slice(), &colors, |x| *x += 1).unwrap();
+    }
+    assert!(data.iter().all(|&x| x == 3));
+}
@@ -1,3 +1,4 @@
+use paradis_core::slice::SliceParAccess;
 use paradis_core::{BoundedParAccess, IntoParAccess, LinearParAccess, ParAccess};
 
 #[test]
@@ -38,3 +39,17 @@ fn test_basic_access() {
         assert_eq!(unsafe { access.get_unsync(3) }, &7);
     }
 }
+
+#[test]
+fn test_shared_access() {
+    let data = vec![0, 1, 2, 3];
+    let access = SliceParAccess::from_slice(&data);
+    assert_eq!(access.collection_len(), 4);
+    assert!(!access.in_bounds(4));
+
+    // Records of a shared access may be obtained repeatedly
+    let access2 = unsafe { access.clone_access() };
+    let a: &u32 = unsafe { access.get_unsync(2) };
+    let b: &u32 = unsafe { access2.get_unsync(2) };
+    assert_eq!((a, b), (&2, &2));
+}
@@ -0,0 +1,135 @@
+use paradis::index::{CheckedUnique, IndexList};
+use paradis::Bounds;
+
+#[test]
+fn check_unique_bounds() {
+    let indices = vec![5usize, 3, 8].check_unique().unwrap();
+    assert_eq!(
+        indices.bounds(),
+        Some(Bounds {
+            offset: 3,
+            extent: 6
+        })
+    );
+
+    let indices = vec![(3usize, 7usize), (1, 9)].check_unique().unwrap();
+    assert_eq!(
+        indices.bounds(),
+        Some(Bounds {
+            offset: (1, 7),
+            extent: (3, 3)
+        })
+    );
+
+    assert!(vec![1usize, 2, 1].check_unique().is_err());
+}
+
+#[test]
+fn check_unique_bounds_at_max() {
+    let indices = vec![usize::MAX, 5].check_unique().unwrap();
+    assert_eq!(
+        indices.bounds(),
+        Some(Bounds {
+            offset: 5,
+            extent: usize::MAX - 4
+        })
+    );
+    assert!(indices.bounds().unwrap().contains_index(usize::MAX));
+
+    let indices = vec![5usize, usize::MAX].check_unique_sorted().unwrap();
+    assert!(indices.bounds().unwrap().contains_index(usize::MAX));
+}
+
+#[test]
+#[should_panic(expected = "extent of bounds must fit in the index type")]
+fn check_unique_bounds_too_large() {
+    // The bounds of all u32 indices have an extent that does not fit in u32
+    let _ = vec![0u32, u32::MAX].check_unique();
+}
+
+#[test]
+fn check_unique_strategies() {
+    // Dense indices (bitmap), sparse indices (sorting) and duplicates in both
+    let dense: Vec<usize> = (0..100).map(|i| (i * 37) % 100).collect();
+    let sparse: Vec<usize> = (0..100).map(|i| i * 1_000_003).collect();
+    let dense_duplicate: Vec<usize> = (0..100).map(|i| (i * 37) % 99).collect();
+    let sparse_duplicate: Vec<usize> = (0..100).map(|i| (i % 99) * 1_000_003).collect();
+
+    for strategy in [
+        CheckedUnique::from_indices,
+        CheckedUnique::from_hashable_indices,
+        CheckedUnique::from_indices_with_sorting,
+        CheckedUnique::from_indices_with_bitmap,
+    ] {
+        let checked = strategy(dense.clone()).unwrap();
+        assert_eq!(
+            checked.bounds(),
+            Some(Bounds {
+                offset: 0,
+                extent: 100
+            })
+        );
+        let checked = strategy(sparse.clone()).unwrap();
+        assert_eq!(
+            checked.bounds(),
+            Some(Bounds {
+                offset: 0,
+                extent: 99 * 1_000_003 + 1
+            })
+        );
+        assert!(strategy(dense_duplicate.clone()).is_err());
+        assert!(strategy(sparse_duplicate.clone()).is_err());
+        assert!(strategy(Vec::new()).is_ok());
+    }
+
+    // Multi-dimensional indices
+    let indices: Vec<(usize, usize)> = vec![(3, 1), (1, 2), (2, 1), (1, 1)];
+    assert!(CheckedUnique::from_indices_with_bitmap(indices.clone()).is_ok());
+    let mut duplicate = indices;
+    duplicate.push((2, 1));
+    assert!(CheckedUnique::from_indices_with_bitmap(duplicate).is_err());
+
+    // Bounded index lists are checked with their own bounds
+    let checked = (0..10).index_product(5..8).check_unique().unwrap();
+    assert_eq!(
+        checked.bounds(),
+        Some(Bounds {
+            offset: (0, 5),
+            extent: (10, 3)
+        })
+    );
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn par_check_unique() {
+    // Large enough to be split across threads
+    let dense: Vec<usize> = (0..100_000).map(|i| (i * 37) % 100_000).collect();
+    let sparse: Vec<usize> = (0..100_000).map(|i| i * 1_000_003).collect();
+    let checked = dense.clone().par_check_unique().unwrap();
+    assert_eq!(checked, CheckedUnique::from_indices(dense.clone()).unwrap());
+    let checked = sparse.clone().par_check_unique().unwrap();
+    assert_eq!(
+        checked,
+        CheckedUnique::from_indices(sparse.clone()).unwrap()
+    );
+
+    let mut dense_duplicate = dense;
+    dense_duplicate[99_999] = 12_345;
+    assert!(dense_duplicate.par_check_unique().is_err());
+    let mut sparse_duplicate = sparse;
+    sparse_duplicate[0] = 77_777 * 1_000_003;
+    assert!(sparse_duplicate.par_check_unique().is_err());
+    assert!(Vec::<usize>::new().par_check_unique().is_ok());
+
+    let checked = (0..300).index_product(5..8).par_check_unique().unwrap();
+    assert_eq!(
+        checked.bounds(),
+        Some(Bounds {
+            offset: (0, 5),
+            extent: (300, 3)
+        })
+    );
+    let duplicate: Vec<(usize, usize)> = vec![(3, 1), (1, 2), (2, 1), (1, 1), (2, 1)];
+    assert!(duplicate.par_check_unique().is_err());
+}
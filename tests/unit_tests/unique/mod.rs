@@ -1 +1,3 @@
+mod checked;
 mod flatten;
+mod sorted;
@@ -0,0 +1,55 @@
+use paradis::index::{collect_indices, IndexList};
+use paradis::Bounds;
+
+#[test]
+fn check_unique_sorted() {
+    let indices = vec![2usize, 3, 5, 8, 13].check_unique_sorted().unwrap();
+    assert_eq!(
+        indices.bounds(),
+        Some(Bounds {
+            offset: 2,
+            extent: 12
+        })
+    );
+    assert_eq!(indices.position(2), Some(0));
+    assert_eq!(indices.position(8), Some(3));
+    assert_eq!(indices.position(13), Some(4));
+    assert_eq!(indices.position(4), None);
+    assert_eq!(indices.position(100), None);
+    assert!(indices.contains(5));
+
+    assert_eq!(
+        vec![1usize, 1].check_unique_sorted().unwrap_err().position,
+        1
+    );
+    let err = vec![1usize, 2, 5, 3].check_unique_sorted().unwrap_err();
+    assert_eq!(err.position, 3);
+    assert_eq!(
+        err.to_string(),
+        "indices are not strictly increasing: the index at position 3 is not greater than the \
+         index at position 2"
+    );
+    assert!(Vec::<usize>::new().check_unique_sorted().is_ok());
+
+    // Tuples are sorted lexicographically, but bounds are still computed along each axis
+    let indices = vec![(0usize, 5usize), (1, 2), (1, 3)]
+        .check_unique_sorted()
+        .unwrap();
+    assert_eq!(
+        indices.bounds(),
+        Some(Bounds {
+            offset: (0, 2),
+            extent: (2, 4)
+        })
+    );
+    assert_eq!(indices.position((1, 2)), Some(1));
+}
+
+#[test]
+fn sort_and_check_unique() {
+    let indices = vec![5usize, 1, 3].sort_and_check_unique().unwrap();
+    assert_eq!(collect_indices::<Vec<_>, _>(&indices), vec![1, 3, 5]);
+    assert_eq!(indices.position(5), Some(2));
+
+    assert!(vec![5usize, 1, 5].sort_and_check_unique().is_err());
+}